@@ -0,0 +1,299 @@
+use crate::config::{Config, ConfigError};
+use std::{
+    io::{self, Read},
+    thread::sleep,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+/// Website the puzzle inputs are fetched from and answers are submitted to.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Year of the puzzles.
+pub const YEAR: u16 = 2023;
+
+/// Default minimum time between two requests.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/xfbs/adventofcode2023 by pelsen@xfbs.net";
+
+/// Error talking to the website.
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("no session token, set AOC_SESSION or `session` in the config file")]
+    MissingSession,
+    #[error("cannot reach {url}, are you offline? ({message})")]
+    Offline { url: String, message: String },
+    #[error("request to {url} failed with status {status}")]
+    Status { url: String, status: u16 },
+    #[error("reading response from {url}: {source}")]
+    Response { url: String, source: io::Error },
+}
+
+/// Rate-limited client for the website, authenticated with the session cookie.
+#[derive(Debug)]
+pub struct Client {
+    /// Base URL of the website.
+    pub base_url: String,
+    /// Session cookie.
+    pub session: Option<String>,
+    /// Minimum time between two requests.
+    pub interval: Duration,
+    last_request: Option<Instant>,
+    agent: ureq::Agent,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client {
+            base_url: DEFAULT_BASE_URL.into(),
+            session: None,
+            interval: DEFAULT_INTERVAL,
+            last_request: None,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+}
+
+impl Client {
+    /// Create a client from the config.
+    pub fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let mut client = Client::default();
+        if let Some(base_url) = &config.base_url {
+            client.base_url = base_url.clone();
+        }
+        if let Some(interval) = config.interval {
+            client.interval = Duration::try_from_secs_f64(interval)
+                .map_err(|_| ConfigError::Interval(interval))?;
+        }
+        client.session = config.session.clone();
+        Ok(client)
+    }
+
+    /// URL of a path relative to the puzzles of this year.
+    pub fn url(&self, path: &str) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        format!("{base}/{YEAR}/{path}")
+    }
+
+    /// Wait until enough time has passed since the previous request.
+    fn wait(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.interval {
+                sleep(self.interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// Send a request, as a `POST` with the form if there is one.
+    fn send(&mut self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, ClientError> {
+        let session = self.session.clone().ok_or(ClientError::MissingSession)?;
+        let url = self.url(path);
+
+        self.wait();
+        let cookie = format!("session={session}");
+        let response = match form {
+            Some(form) => self.agent.post(&url).set("Cookie", &cookie).send_form(form),
+            None => self.agent.get(&url).set("Cookie", &cookie).call(),
+        };
+
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, _)) => return Err(ClientError::Status { url, status }),
+            Err(ureq::Error::Transport(error)) => {
+                return Err(ClientError::Offline {
+                    url,
+                    message: error.to_string(),
+                })
+            }
+        };
+
+        let mut body = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .map_err(|source| ClientError::Response { url, source })?;
+        Ok(body)
+    }
+
+    /// Get a page.
+    pub fn get(&mut self, path: &str) -> Result<String, ClientError> {
+        self.send(path, None)
+    }
+
+    /// Post a form to a page.
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        self.send(path, Some(form))
+    }
+}
+
+/// Serve the given responses on a local port, one per connection. Returns the
+/// base URL and a receiver for the raw requests.
+#[cfg(test)]
+pub(crate) fn serve(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, std::sync::mpsc::Receiver<String>) {
+    use std::{io::Write, net::TcpListener};
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            let mut expected = None;
+            loop {
+                if expected.is_none() {
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let length = text
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(": ")?;
+                                name.eq_ignore_ascii_case("content-length")
+                                    .then(|| value.trim().parse::<usize>().ok())?
+                            })
+                            .unwrap_or(0);
+                        expected = Some(end + 4 + length);
+                    }
+                }
+                if expected.is_some_and(|expected| request.len() >= expected) {
+                    break;
+                }
+                let length = stream.read(&mut buffer).unwrap();
+                if length == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..length]);
+            }
+            sender.send(String::from_utf8(request).unwrap()).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    (url, receiver)
+}
+
+/// Client for a local test server, without rate limiting.
+#[cfg(test)]
+pub(crate) fn local(url: String) -> Client {
+    Client {
+        base_url: url,
+        session: Some("secret".into()),
+        interval: Duration::ZERO,
+        ..Client::default()
+    }
+}
+
+#[test]
+fn can_send_requests() {
+    let (url, requests) = serve(vec![(200, "input"), (200, "answer")]);
+    let mut client = local(url);
+
+    assert_eq!(client.get("day/1/input").unwrap(), "input");
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("session=secret"));
+
+    assert_eq!(
+        client
+            .post("day/1/answer", &[("level", "1"), ("answer", "42")])
+            .unwrap(),
+        "answer"
+    );
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("level=1&answer=42"));
+}
+
+#[test]
+fn can_report_errors() {
+    let (url, _requests) = serve(vec![(500, "broken")]);
+    let mut client = Client {
+        base_url: url,
+        session: Some("secret".into()),
+        ..Client::default()
+    };
+    assert!(matches!(
+        client.get("day/1/input"),
+        Err(ClientError::Status { status: 500, .. })
+    ));
+
+    client.session = None;
+    assert!(matches!(
+        client.get("day/1/input"),
+        Err(ClientError::MissingSession)
+    ));
+}
+
+#[test]
+fn can_detect_offline() {
+    // bind and drop a listener to find a port nothing is listening on
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let mut client = Client {
+        base_url: format!("http://127.0.0.1:{port}"),
+        session: Some("secret".into()),
+        ..Client::default()
+    };
+
+    let error = client.get("day/1/input").unwrap_err();
+    assert!(matches!(error, ClientError::Offline { .. }));
+    assert!(error.to_string().contains("are you offline?"));
+}
+
+#[test]
+fn can_rate_limit() {
+    let (url, _requests) = serve(vec![(200, "a"), (200, "b")]);
+    let mut client = Client {
+        base_url: url,
+        session: Some("secret".into()),
+        interval: Duration::from_millis(300),
+        ..Client::default()
+    };
+
+    let start = Instant::now();
+    assert_eq!(client.get("day/1/input").unwrap(), "a");
+    assert_eq!(client.get("day/2/input").unwrap(), "b");
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[test]
+fn can_configure() {
+    let client = Client::from_config(&Config {
+        base_url: Some("http://localhost/".into()),
+        interval: Some(0.5),
+        ..Config::default()
+    })
+    .unwrap();
+    assert_eq!(
+        client.url("day/6/input"),
+        "http://localhost/2023/day/6/input"
+    );
+    assert_eq!(client.interval, Duration::from_millis(500));
+
+    for interval in [-1.0, f64::NAN, f64::INFINITY] {
+        let config = Config {
+            interval: Some(interval),
+            ..Config::default()
+        };
+        assert!(matches!(
+            Client::from_config(&config),
+            Err(ConfigError::Interval(_))
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+use serde::Deserialize;
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Error loading the config file.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("reading {path}: {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("parsing {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("invalid interval {0}, expected a number of seconds")]
+    Interval(f64),
+}
+
+/// Settings for talking to the website, as stored in the config file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Session cookie used to fetch inputs and submit answers.
+    pub session: Option<String>,
+    /// Base URL of the website.
+    pub base_url: Option<String>,
+    /// Directory the inputs and submitted answers are kept in.
+    pub cache: Option<PathBuf>,
+    /// Minimum time between two requests, in seconds.
+    pub interval: Option<f64>,
+}
+
+impl Config {
+    /// Load the config file, a missing file results in the default config.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let data = match std::fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(source) => {
+                return Err(ConfigError::Read {
+                    path: path.into(),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&data).map_err(|source| ConfigError::Parse {
+            path: path.into(),
+            source,
+        })
+    }
+
+    /// Override the session and base URL with the `AOC_SESSION` and
+    /// `AOC_BASE_URL` environment variables, if they are set.
+    pub fn with_env(mut self) -> Self {
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            self.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            self.base_url = Some(base_url);
+        }
+        self
+    }
+
+    /// Directory the inputs and submitted answers are kept in.
+    pub fn cache(&self) -> PathBuf {
+        self.cache.clone().unwrap_or_else(|| "inputs".into())
+    }
+}
+
+#[test]
+fn can_load_config() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("aoc.toml");
+    assert_eq!(Config::load(&path).unwrap(), Config::default());
+    assert_eq!(Config::default().cache(), Path::new("inputs"));
+
+    std::fs::write(
+        &path,
+        "session = \"secret\"\nbase_url = \"http://localhost\"\ninterval = 0.5\n",
+    )
+    .unwrap();
+    let config = Config::load(&path).unwrap();
+    assert_eq!(config.session.as_deref(), Some("secret"));
+    assert_eq!(config.base_url.as_deref(), Some("http://localhost"));
+    assert_eq!(config.interval, Some(0.5));
+
+    std::fs::write(&path, "unknown = 1\n").unwrap();
+    assert!(matches!(
+        Config::load(&path),
+        Err(ConfigError::Parse { .. })
+    ));
+}
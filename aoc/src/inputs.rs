@@ -0,0 +1,106 @@
+use crate::client::{Client, ClientError};
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+/// Error getting a puzzle input.
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error("input for day {0} is not cached: {1}")]
+    Client(u8, #[source] ClientError),
+    #[error("input for day {day} is not available at {url}")]
+    NotFound { day: u8, url: String },
+    #[error("accessing cache at {path}: {source}")]
+    Cache { path: PathBuf, source: io::Error },
+}
+
+/// Puzzle inputs, cached on disk and fetched from the website on a miss.
+#[derive(Debug)]
+pub struct Inputs {
+    /// Directory containing the cached `dayNN.txt` files.
+    pub cache: PathBuf,
+    /// Client used to fetch missing inputs.
+    pub client: Client,
+}
+
+impl Inputs {
+    /// Create an input cache in the given directory.
+    pub fn new(cache: impl Into<PathBuf>, client: Client) -> Self {
+        Inputs {
+            cache: cache.into(),
+            client,
+        }
+    }
+
+    /// Path of the cached input for a day.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache.join(format!("day{day:02}.txt"))
+    }
+
+    /// Get the input for a day from the cache, fetching it on a miss.
+    pub fn get(&mut self, day: u8) -> Result<String, FetchError> {
+        let path = self.path(day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => return Err(FetchError::Cache { path, source }),
+        }
+
+        let input = self.fetch(day)?;
+        let cache = |source| FetchError::Cache {
+            path: path.clone(),
+            source,
+        };
+        std::fs::create_dir_all(&self.cache).map_err(cache)?;
+        std::fs::write(&path, &input).map_err(cache)?;
+        Ok(input)
+    }
+
+    /// Fetch the input for a day from the website, bypassing the cache.
+    pub fn fetch(&mut self, day: u8) -> Result<String, FetchError> {
+        match self.client.get(&format!("day/{day}/input")) {
+            Ok(input) => Ok(input),
+            Err(ClientError::Status { status: 404, url }) => Err(FetchError::NotFound { day, url }),
+            Err(error) => Err(FetchError::Client(day, error)),
+        }
+    }
+}
+
+#[test]
+fn can_fetch_and_cache() {
+    let (url, requests) = crate::client::serve(vec![(200, "0 3 6 9 12 15\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let mut inputs = Inputs::new(cache.path().join("inputs"), crate::client::local(url));
+
+    assert_eq!(inputs.get(9).unwrap(), "0 3 6 9 12 15\n");
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+    assert!(inputs.path(9).ends_with("day09.txt"));
+    assert!(inputs.path(9).exists());
+
+    // second lookup is served from the cache, without a request
+    assert_eq!(inputs.get(9).unwrap(), "0 3 6 9 12 15\n");
+    assert!(requests.try_recv().is_err());
+}
+
+#[test]
+fn can_report_errors() {
+    let (url, _requests) = crate::client::serve(vec![(404, "not yet")]);
+    let cache = tempfile::tempdir().unwrap();
+    let mut inputs = Inputs::new(cache.path().join("inputs"), crate::client::local(url));
+
+    assert!(matches!(
+        inputs.get(1),
+        Err(FetchError::NotFound { day: 1, .. })
+    ));
+    assert!(!inputs.path(1).exists());
+
+    inputs.client.session = None;
+    let error = inputs.get(3).unwrap_err();
+    assert!(matches!(
+        error,
+        FetchError::Client(3, ClientError::MissingSession)
+    ));
+    assert!(error
+        .to_string()
+        .starts_with("input for day 3 is not cached"));
+}
@@ -0,0 +1,36 @@
+//! Runner for all of the Advent of Code solutions.
+//!
+//! Every day crate implements the [`Solution`](aoc_common::Solution) trait. The
+//! [`registry`] contains a type-erased entry for each of them, which the
+//! [`runner`] uses to solve a day by number. Puzzle inputs are cached and
+//! fetched by [`inputs`], and answers are submitted by [`submit`], both using
+//! the [`client`] for the website. Known answers are checked by
+//! [`verify`], which [`progress`] uses for the README table. New days are
+//! generated by [`scaffold`].
+
+pub mod client;
+pub mod config;
+pub mod inputs;
+pub mod progress;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
+
+use std::io;
+use thiserror::Error;
+
+/// Error running a solution.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("day {0} is not implemented")]
+    UnknownDay(u8),
+    #[error("reading input: {0}")]
+    Input(#[from] io::Error),
+    #[error("day {day}: {source}")]
+    Solve {
+        day: u8,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
@@ -0,0 +1,335 @@
+use aoc::{
+    client::Client,
+    config::Config,
+    inputs::Inputs,
+    progress::{progress, table as progress_table, update},
+    registry::{self, DAYS},
+    runner::{table, Part, Report},
+    scaffold::scaffold,
+    submit::{submit, History},
+    verify::{verify, Answers, Outcome},
+};
+use aoc_common::input;
+use clap::{Parser, Subcommand};
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    process::exit,
+    str::FromStr,
+};
+
+#[derive(Parser, Debug)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+struct Options {
+    /// Config file with the session token and input cache settings.
+    #[arg(long, global = true, default_value = "aoc.toml")]
+    config: PathBuf,
+
+    /// Directory the puzzle inputs are cached in.
+    #[arg(long, global = true)]
+    cache: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve a day, or all implemented days.
+    Run {
+        /// Day to run (`1` to `25`), or `all`.
+        day: Selection,
+
+        /// Only run this part.
+        #[arg(long, short)]
+        part: Option<Part>,
+
+        /// Input file, or `-` for standard input. By default, the input is
+        /// read from the cache and fetched if it is missing.
+        #[arg(long, short)]
+        input: Option<PathBuf>,
+
+        /// Print a table with the wall time of every stage.
+        #[arg(long, short)]
+        time: bool,
+
+        /// Print the answers and timings as JSON.
+        #[arg(long, conflicts_with = "time")]
+        json: bool,
+    },
+    /// Fetch the puzzle input of a day, or all implemented days, into the
+    /// cache.
+    Fetch {
+        /// Day to fetch (`1` to `25`), or `all`.
+        day: Selection,
+    },
+    /// Submit the answer to a part. Answers that are known to be wrong from
+    /// previous attempts are not submitted.
+    Submit {
+        /// Day to submit the answer for.
+        day: u8,
+
+        /// Part to submit the answer for.
+        part: Part,
+
+        /// Answer to submit, computed from the cached input if missing.
+        answer: Option<String>,
+    },
+    /// Check all days against the known answers.
+    Verify {
+        /// File containing the known answers, input paths are relative to it.
+        #[arg(long, short, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Rewrite the progress table in the README, from the implemented parts
+    /// and the known answers.
+    Readme {
+        /// README file containing the progress table marker comments.
+        #[arg(long, default_value = "README.md")]
+        readme: PathBuf,
+
+        /// File containing the known answers, input paths are relative to it.
+        #[arg(long, short, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// Only check that the table is current, without rewriting it.
+        #[arg(long)]
+        check: bool,
+    },
+    /// Generate the crate for a new day under `solutions/` and register it
+    /// with the runner. Existing days are never overwritten.
+    New {
+        /// Day to generate (`1` to `25`).
+        day: u8,
+
+        /// Root of the workspace.
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+/// How to print the reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Answers,
+    Time,
+    Json,
+}
+
+/// Which days to run.
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "all" => Ok(Selection::All),
+            day => day
+                .parse()
+                .map(Selection::Day)
+                .map_err(|_| format!("invalid day {day:?}, expected a number or `all`")),
+        }
+    }
+}
+
+fn print(report: &Report) {
+    for part in &report.parts {
+        let answer = part.answer.as_deref().unwrap_or("unsolved");
+        println!("day{:02} part{} {answer}", report.day, part.part);
+    }
+}
+
+fn run(
+    inputs: &mut Inputs,
+    selection: Selection,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    output: Output,
+) -> Result<(), Box<dyn Error>> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut reports = vec![];
+    match selection {
+        Selection::Day(day) => {
+            let entry = registry::get(day)?;
+            let input = match input {
+                Some(path) => input::read(Some(&path))?,
+                None => inputs.get(day)?,
+            };
+            reports.push(entry.run(&input, &parts)?);
+        }
+        Selection::All if input.is_some() => {
+            return Err("cannot use --input when running all days, use --cache instead".into());
+        }
+        Selection::All => {
+            for day in DAYS {
+                match inputs.get(day.day()) {
+                    Ok(input) => reports.push(day.run(&input, &parts)?),
+                    Err(error) => eprintln!("day{:02} skipped, {error}", day.day()),
+                }
+            }
+        }
+    }
+
+    match output {
+        Output::Answers => reports.iter().for_each(print),
+        Output::Time => {
+            reports.iter().for_each(print);
+            println!();
+            print!("{}", table(&reports));
+        }
+        Output::Json => println!("{}", serde_json::to_string_pretty(&reports).unwrap()),
+    }
+
+    Ok(())
+}
+
+fn fetch(inputs: &mut Inputs, selection: Selection) -> Result<(), Box<dyn Error>> {
+    let days = match selection {
+        Selection::Day(day) => vec![day],
+        Selection::All => DAYS.iter().map(|day| day.day()).collect(),
+    };
+
+    for day in days {
+        inputs.get(day)?;
+        println!("day{day:02} {}", inputs.path(day).display());
+    }
+
+    Ok(())
+}
+
+fn submit_answer(
+    inputs: &mut Inputs,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry = registry::get(day)?;
+            let report = entry.run(&inputs.get(day)?, &[part])?;
+            report
+                .part(part)
+                .and_then(|part| part.answer.clone())
+                .ok_or_else(|| format!("day {day} part {part} is not solved yet"))?
+        }
+    };
+
+    let mut history = History::load(inputs.cache.join("submissions.json"))?;
+    println!("day{day:02} part{part} submitting {answer}");
+    let outcome = submit(&mut inputs.client, &mut history, day, part, &answer)?;
+    println!("day{day:02} part{part} {outcome}");
+    Ok(())
+}
+
+/// Check the known answers, failing if any of them is wrong.
+fn check(path: &Path) -> Result<(), Box<dyn Error>> {
+    let answers = Answers::parse(&std::fs::read_to_string(path)?)?;
+    let root = path.parent().unwrap_or(Path::new("."));
+
+    let (mut checked, mut skipped, mut failures) = (0, 0, 0);
+    for (expected, outcome) in verify(&answers, root) {
+        println!(
+            "day{:02} part{} {}: {outcome}",
+            expected.day,
+            expected.part,
+            expected.input.display()
+        );
+        match outcome {
+            Outcome::Skipped => skipped += 1,
+            _ => checked += 1,
+        }
+        if outcome.is_failure() {
+            println!("  expected {}", expected.answer);
+            failures += 1;
+        }
+    }
+
+    println!("{checked} answers checked, {failures} failed, {skipped} skipped");
+    match failures {
+        0 => Ok(()),
+        failures => Err(format!("{failures} answers are wrong").into()),
+    }
+}
+
+fn readme(path: &Path, answers: &Path, check: bool) -> Result<(), Box<dyn Error>> {
+    let readme = std::fs::read_to_string(path)?;
+    let root = answers.parent().unwrap_or(Path::new("."));
+    let answers = Answers::parse(&std::fs::read_to_string(answers)?)?;
+    let updated = update(&readme, &progress_table(&progress(&answers, root)))?;
+
+    match (readme == updated, check) {
+        (true, _) => println!("{} is current", path.display()),
+        (false, true) => return Err(format!("{} is outdated", path.display()).into()),
+        (false, false) => {
+            std::fs::write(path, updated)?;
+            println!("updated {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn new(root: &Path, day: u8) -> Result<(), Box<dyn Error>> {
+    for path in scaffold(root, day)? {
+        println!("wrote {}", path.display());
+    }
+    println!("run `aoc readme` to update the progress table once day {day} is solved");
+    Ok(())
+}
+
+fn main() {
+    let options = Options::parse();
+    let config = match Config::load(&options.config) {
+        Ok(config) => config.with_env(),
+        Err(error) => {
+            eprintln!("error: {error}");
+            exit(1);
+        }
+    };
+    let client = Client::from_config(&config).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        exit(1);
+    });
+    let cache = options.cache.unwrap_or_else(|| config.cache());
+    let mut inputs = Inputs::new(cache, client);
+
+    let result = match options.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            time,
+            json,
+        } => {
+            let output = match (time, json) {
+                (_, true) => Output::Json,
+                (true, _) => Output::Time,
+                _ => Output::Answers,
+            };
+            run(&mut inputs, day, part, input, output)
+        }
+        Command::Fetch { day } => fetch(&mut inputs, day),
+        Command::Submit { day, part, answer } => submit_answer(&mut inputs, day, part, answer),
+        Command::Verify { answers } => check(&answers),
+        Command::Readme {
+            readme: path,
+            answers,
+            check,
+        } => readme(&path, &answers, check),
+        Command::New { day, root } => new(&root, day),
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {error}");
+        exit(1);
+    }
+}
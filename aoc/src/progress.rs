@@ -0,0 +1,234 @@
+use crate::{
+    registry,
+    runner::Part,
+    verify::{verify, Answers, Outcome},
+};
+use std::{fmt::Display, path::Path};
+use thiserror::Error;
+
+/// Comment marking the start of the generated table in the README.
+pub const START: &str = "<!-- progress start -->";
+/// Comment marking the end of the generated table in the README.
+pub const END: &str = "<!-- progress end -->";
+
+/// Puzzle titles, by day.
+const TITLES: [&str; 25] = [
+    "Trebuchet?!",
+    "Cube Conundrum",
+    "Gear Ratios",
+    "Scratchcards",
+    "If You Give A Seed A Fertilizer",
+    "Wait For It",
+    "Camel Cards",
+    "Haunted Wasteland",
+    "Mirage Maintenance",
+    "Pipe Maze",
+    "Cosmic Expansion",
+    "Hot Springs",
+    "Point of Incidence",
+    "Parabolic Reflector Dish",
+    "Lens Library",
+    "The Floor Will Be Lava",
+    "Clumsy Crucible",
+    "Lavaduct Lagoon",
+    "Aplenty",
+    "Pulse Propagation",
+    "Step Counter",
+    "Sand Slabs",
+    "A Long Walk",
+    "Never Tell Me The Odds",
+    "Snowverload",
+];
+
+/// Error updating the progress table.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ProgressError {
+    #[error("missing marker comment {0}")]
+    MissingMarker(&'static str),
+}
+
+/// Status of one part of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Part is not implemented.
+    Missing,
+    /// Part is implemented, but there are no known answers to check it with.
+    Unverified,
+    /// Part is implemented and produces all known answers.
+    Verified,
+    /// Part is implemented, but fails to produce some known answer.
+    Failing,
+}
+
+impl Status {
+    /// Combine the status with the outcome of checking a known answer.
+    fn check(self, outcome: &Outcome) -> Self {
+        match (self, outcome) {
+            (Status::Missing | Status::Failing, _) => self,
+            (_, Outcome::Skipped) => self,
+            (_, Outcome::Pass) => Status::Verified,
+            (_, _) => Status::Failing,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Missing => write!(f, "❌"),
+            Status::Unverified => write!(f, "❔"),
+            Status::Verified => write!(f, "✔️"),
+            Status::Failing => write!(f, "⚠️"),
+        }
+    }
+}
+
+/// Progress of a single day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    pub day: u8,
+    pub parts: [Status; 2],
+}
+
+/// Determine the progress of every day of the calendar, checking the known
+/// answers with inputs resolved relative to `root`.
+pub fn progress(answers: &Answers, root: &Path) -> Vec<Progress> {
+    let mut days: Vec<Progress> = (1..=TITLES.len() as u8)
+        .map(|day| Progress {
+            day,
+            parts: Part::ALL.map(|part| match registry::get(day) {
+                Ok(entry) if entry.solves(part) => Status::Unverified,
+                _ => Status::Missing,
+            }),
+        })
+        .collect();
+
+    for (expected, outcome) in verify(answers, root) {
+        if let Some(day) = days.iter_mut().find(|day| day.day == expected.day) {
+            let status = &mut day.parts[expected.part.number() as usize - 1];
+            *status = status.check(&outcome);
+        }
+    }
+
+    days
+}
+
+/// Render the progress as a Markdown table.
+pub fn table(days: &[Progress]) -> String {
+    let mut output = String::from("| Problem | Part I | Part II |\n| --- | --- | --- |\n");
+    for Progress { day, parts } in days {
+        let title = TITLES.get(*day as usize - 1).copied().unwrap_or("?");
+        output.push_str(&format!(
+            "| [Day {day}: {title}](https://adventofcode.com/2023/day/{day}) | {} | {} |\n",
+            parts[0], parts[1]
+        ));
+    }
+    output.push_str(&format!(
+        "\n{} verified, {} implemented without known answers, {} failing, {} missing.\n",
+        Status::Verified,
+        Status::Unverified,
+        Status::Failing,
+        Status::Missing
+    ));
+    output
+}
+
+/// Replace the contents between the marker comments with the table.
+pub fn update(readme: &str, table: &str) -> Result<String, ProgressError> {
+    let start = readme
+        .find(START)
+        .ok_or(ProgressError::MissingMarker(START))?
+        + START.len();
+    let end = start
+        + readme[start..]
+            .find(END)
+            .ok_or(ProgressError::MissingMarker(END))?;
+    Ok(format!(
+        "{}\n\n{table}\n{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+#[test]
+fn can_get_progress() {
+    let answers = Answers::parse(include_str!("../../answers.toml")).unwrap();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let days = progress(&answers, &root);
+    assert_eq!(days[0].parts, [Status::Verified; 2]);
+    assert_eq!(days[3].parts, [Status::Verified, Status::Missing]);
+    assert_eq!(days[5].parts, [Status::Missing; 2]);
+    assert_eq!(days.len(), 25);
+    assert_eq!(days[24].day, 25);
+    assert_eq!(days[24].parts, [Status::Missing; 2]);
+}
+
+#[test]
+fn can_detect_failing() {
+    let answers = Answers::parse(
+        r#"
+        [[answer]]
+        day = 9
+        part = 2
+        input = "solutions/day09/sample.txt"
+        answer = "3"
+
+        [[answer]]
+        day = 9
+        part = 2
+        input = "solutions/day09/sample.txt"
+        answer = "2"
+        "#,
+    )
+    .unwrap();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let days = progress(&answers, &root);
+    assert_eq!(days[8].parts, [Status::Unverified, Status::Failing]);
+}
+
+#[test]
+fn can_render_table() {
+    let days = [
+        Progress {
+            day: 1,
+            parts: [Status::Verified, Status::Unverified],
+        },
+        Progress {
+            day: 2,
+            parts: [Status::Failing, Status::Missing],
+        },
+    ];
+    let table = table(&days);
+    assert!(table.starts_with("| Problem | Part I | Part II |\n| --- | --- | --- |\n"));
+    assert!(table.contains(
+        "| [Day 1: Trebuchet?!](https://adventofcode.com/2023/day/1) | ✔️ | ❔ |\n\
+         | [Day 2: Cube Conundrum](https://adventofcode.com/2023/day/2) | ⚠️ | ❌ |\n"
+    ));
+}
+
+#[test]
+fn can_update() {
+    let readme = format!("# Title\n\n{START}\nold table\n{END}\n\n## Running\n");
+    let updated = update(&readme, "new table\n").unwrap();
+    assert_eq!(
+        updated,
+        format!("# Title\n\n{START}\n\nnew table\n\n{END}\n\n## Running\n")
+    );
+    assert_eq!(update(&updated, "new table\n").unwrap(), updated);
+    assert_eq!(
+        update("# Title\n", ""),
+        Err(ProgressError::MissingMarker(START))
+    );
+}
+
+#[test]
+fn readme_is_current() {
+    let answers = Answers::parse(include_str!("../../answers.toml")).unwrap();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let readme = include_str!("../../README.md");
+    let expected = update(readme, &table(&progress(&answers, &root))).unwrap();
+    assert!(
+        readme == expected,
+        "README.md progress table is outdated, run `aoc readme`"
+    );
+}
@@ -0,0 +1,77 @@
+use crate::{
+    runner::{run, Part, Report},
+    Error,
+};
+use aoc_common::{Answer, Solution};
+
+/// Type-erased entry for a single day.
+#[derive(Clone, Copy)]
+pub struct Day {
+    day: u8,
+    solved: [bool; 2],
+    run: fn(&str, &[Part]) -> Result<Report, Error>,
+}
+
+impl Day {
+    /// Create an entry for the given solution.
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            solved: [S::Part1::SOLVED, S::Part2::SOLVED],
+            run: run::<S>,
+        }
+    }
+
+    /// Day of the puzzle.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Whether the part has a solution, rather than being unsolved.
+    pub fn solves(&self, part: Part) -> bool {
+        self.solved[part.number() as usize - 1]
+    }
+
+    /// Parse the input and solve the requested parts.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Report, Error> {
+        (self.run)(input, parts)
+    }
+}
+
+macro_rules! days {
+    ($($solution:path),* $(,)?) => {
+        /// All implemented days, in order.
+        pub static DAYS: &[Day] = &[$(Day::new::<$solution>()),*];
+    };
+}
+
+days![
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day08::Day08,
+    day09::Day09,
+];
+
+/// Look up the entry for a day.
+pub fn get(day: u8) -> Result<&'static Day, Error> {
+    DAYS.iter()
+        .find(|entry| entry.day == day)
+        .ok_or(Error::UnknownDay(day))
+}
+
+#[test]
+fn days_are_sorted() {
+    assert!(DAYS.windows(2).all(|days| days[0].day < days[1].day));
+}
+
+#[test]
+fn can_get() {
+    assert_eq!(get(1).unwrap().day(), 1);
+    assert_eq!(get(9).unwrap().day(), 9);
+    assert!(get(2).unwrap().solves(Part::One));
+    assert!(!get(4).unwrap().solves(Part::Two));
+    assert!(matches!(get(26), Err(Error::UnknownDay(26))));
+}
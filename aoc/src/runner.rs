@@ -0,0 +1,237 @@
+use crate::Error;
+use aoc_common::{Answer, Solution};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize, Serializer};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// Part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        part.number()
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            other => Err(format!("invalid part {other}, expected 1 or 2")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Answer to a single part, `None` if the part is not solved yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Option<String>,
+    /// Wall time spent solving this part.
+    #[serde(rename = "duration_ns", serialize_with = "nanos")]
+    pub duration: Duration,
+}
+
+/// Answers and timings for one day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    /// Wall time spent parsing the input.
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    /// Get the report for a part, if it was run.
+    pub fn part(&self, part: Part) -> Option<&PartReport> {
+        self.parts.iter().find(|report| report.part == part)
+    }
+
+    /// Total wall time of all stages.
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .map(|part| part.duration)
+                .sum::<Duration>()
+    }
+}
+
+fn answer(answer: impl Answer) -> Option<String> {
+    answer.is_solved().then(|| answer.to_string())
+}
+
+fn solve_error<S: Solution>(error: S::Error) -> Error {
+    Error::Solve {
+        day: S::DAY,
+        source: error.into(),
+    }
+}
+
+/// Parse the input once and solve the requested parts, timing every stage.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, Error> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(solve_error::<S>)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed).map(answer),
+                Part::Two => S::part2(&parsed).map(answer),
+            };
+            let duration = start.elapsed();
+            answer
+                .map(|answer| PartReport {
+                    part,
+                    answer,
+                    duration,
+                })
+                .map_err(solve_error::<S>)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Report {
+        day: S::DAY,
+        parse,
+        parts,
+    })
+}
+
+/// Render the timings of the reports as a table.
+pub fn table(reports: &[Report]) -> String {
+    let duration = |duration: Option<Duration>| match duration {
+        Some(duration) => format!("{duration:.2?}"),
+        None => "-".into(),
+    };
+
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Parse".into(),
+        "Part 1".into(),
+        "Part 2".into(),
+        "Total".into(),
+    ]];
+    for report in reports {
+        rows.push([
+            format!("{:02}", report.day),
+            duration(Some(report.parse)),
+            duration(report.part(Part::One).map(|part| part.duration)),
+            duration(report.part(Part::Two).map(|part| part.duration)),
+            duration(Some(report.total())),
+        ]);
+    }
+
+    let mut widths = [0; 5];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+    for row in &rows {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:>width$}"))
+            .collect();
+        output.push_str(line.join(" | ").trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+#[test]
+fn can_run() {
+    let report = run::<day09::Day09>("0 3 6 9 12 15\n", &Part::ALL).unwrap();
+    assert_eq!(report.day, 9);
+    assert_eq!(report.parts.len(), 2);
+    assert_eq!(
+        report.part(Part::One).unwrap().answer.as_deref(),
+        Some("18")
+    );
+    assert_eq!(
+        report.part(Part::Two).unwrap().answer.as_deref(),
+        Some("-3")
+    );
+
+    let report = run::<day04::Day04>("Card 1: 1 2 | 2 3\n", &[Part::Two]).unwrap();
+    assert_eq!(report.parts.len(), 1);
+    assert!(report.part(Part::One).is_none());
+    assert_eq!(report.part(Part::Two).unwrap().answer, None);
+
+    assert!(matches!(
+        run::<day09::Day09>("1 x\n", &Part::ALL),
+        Err(Error::Solve { day: 9, .. })
+    ));
+}
+
+#[test]
+fn can_render() {
+    let report = Report {
+        day: 3,
+        parse: Duration::from_micros(1500),
+        parts: vec![PartReport {
+            part: Part::One,
+            answer: Some("4361".into()),
+            duration: Duration::from_micros(250),
+        }],
+    };
+    assert_eq!(report.total(), Duration::from_micros(1750));
+
+    let table = table(std::slice::from_ref(&report));
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Day |"));
+    assert!(lines[1].starts_with(" 03 |"));
+    assert!(lines[1].contains("1.50ms"));
+    assert!(lines[1].contains("250.00µs"));
+    assert!(lines[1].contains(" - "));
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["day"], 3);
+    assert_eq!(json["parse_ns"], 1_500_000);
+    assert_eq!(json["parts"][0]["part"], 1);
+    assert_eq!(json["parts"][0]["answer"], "4361");
+    assert_eq!(json["parts"][0]["duration_ns"], 250_000);
+}
@@ -0,0 +1,197 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Error generating the crate for a day.
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("invalid day {0}, expected 1 to 25")]
+    InvalidDay(u8),
+    #[error("day {day} already exists at {path}")]
+    Exists { day: u8, path: PathBuf },
+    #[error("cannot find {0} in {1}")]
+    Marker(&'static str, PathBuf),
+    #[error("writing {path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+}
+
+/// Templates for the files of a day crate, with `dayNN`, `DayNN` and `{day}`
+/// as placeholders.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.in")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.in")),
+    ("src/main.rs", include_str!("../templates/main.rs.in")),
+    ("benches/dayNN.rs", include_str!("../templates/bench.rs.in")),
+    ("sample.txt", ""),
+];
+
+/// Fill in the placeholders of a template.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("dayNN", &format!("day{day:02}"))
+        .replace("DayNN", &format!("Day{day:02}"))
+        .replace("{day}", &day.to_string())
+}
+
+/// Add the day to the sorted `days!` list of the registry.
+fn register(registry: &str, day: u8) -> Option<String> {
+    let line = format!("    day{day:02}::Day{day:02},");
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let first = lines.iter().position(|l| l.starts_with("days!["))? + 1;
+    let last = first + lines[first..].iter().position(|l| l.starts_with("];"))?;
+    let index = first + lines[first..last].partition_point(|l| **l < *line);
+    lines.insert(index, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add the day crate to the dependencies of the runner, after the other days
+/// or at the end of the dependencies.
+fn depend(manifest: &str, day: u8) -> Option<String> {
+    let line = format!("day{day:02} = {{ path = \"../solutions/day{day:02}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let first = lines.iter().position(|l| *l == "[dependencies]")? + 1;
+    let last = first
+        + lines[first..]
+            .iter()
+            .position(|l| l.trim().is_empty() || l.starts_with('['))
+            .unwrap_or(lines.len() - first);
+    let days = &lines[first..last];
+    let index = match days.iter().position(|l| l.starts_with("day")) {
+        Some(start) => first + start + days[start..].partition_point(|l| **l < *line),
+        None => last,
+    };
+    lines.insert(index, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+fn write(path: &Path, data: &str) -> Result<(), ScaffoldError> {
+    let error = |source| ScaffoldError::Io {
+        path: path.into(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(error)?;
+    }
+    std::fs::write(path, data).map_err(error)
+}
+
+/// Generate the crate for a day under `solutions/` in the workspace root and
+/// register it with the runner, returning the paths written.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let path = root.join(format!("solutions/day{day:02}"));
+    if path.exists() {
+        return Err(ScaffoldError::Exists { day, path });
+    }
+
+    // update the runner first, so that nothing is written if it fails
+    let registry_path = root.join("aoc/src/registry.rs");
+    let registry = register(&read(&registry_path)?, day)
+        .ok_or_else(|| ScaffoldError::Marker("days![", registry_path.clone()))?;
+    let manifest_path = root.join("aoc/Cargo.toml");
+    let manifest = depend(&read(&manifest_path)?, day)
+        .ok_or_else(|| ScaffoldError::Marker("[dependencies]", manifest_path.clone()))?;
+
+    let mut written = vec![];
+    for (name, template) in TEMPLATES {
+        let file = path.join(render(name, day));
+        write(&file, &render(template, day))?;
+        written.push(file);
+    }
+    write(&registry_path, &registry)?;
+    written.push(registry_path);
+    write(&manifest_path, &manifest)?;
+    written.push(manifest_path);
+
+    Ok(written)
+}
+
+#[test]
+fn can_render() {
+    let lib = render(TEMPLATES[1].1, 6);
+    assert!(lib.contains("pub struct Day06;"));
+    assert!(lib.contains("const DAY: u8 = 6;"));
+    assert!(!lib.contains("NN"));
+    assert_eq!(render("benches/dayNN.rs", 12), "benches/day12.rs");
+}
+
+#[cfg(test)]
+const REGISTRY: &str = "use crate::Day;\n\ndays![\n    day01::Day01,\n    day08::Day08,\n];\n";
+
+#[cfg(test)]
+const MANIFEST: &str = "[package]\nname = \"aoc\"\n\n[dependencies]\nclap.workspace = true\n";
+
+#[test]
+fn can_register() {
+    let registry = register(REGISTRY, 6).unwrap();
+    assert!(
+        registry.contains("days![\n    day01::Day01,\n    day06::Day06,\n    day08::Day08,\n];")
+    );
+    let registry = register(&registry, 12).unwrap();
+    assert!(registry.contains("    day08::Day08,\n    day12::Day12,\n];"));
+    assert!(register("days!{}", 1).is_none());
+
+    let manifest = depend(MANIFEST, 8).unwrap();
+    assert!(
+        manifest.ends_with("clap.workspace = true\nday08 = { path = \"../solutions/day08\" }\n")
+    );
+    let manifest = depend(&manifest, 1).unwrap();
+    assert!(manifest.ends_with(
+        "clap.workspace = true\n\
+         day01 = { path = \"../solutions/day01\" }\n\
+         day08 = { path = \"../solutions/day08\" }\n"
+    ));
+    let manifest = depend(&manifest, 9).unwrap();
+    assert!(manifest.ends_with(
+        "day08 = { path = \"../solutions/day08\" }\nday09 = { path = \"../solutions/day09\" }\n"
+    ));
+}
+
+#[test]
+fn can_scaffold() {
+    let directory = tempfile::tempdir().unwrap();
+    let root = directory.path();
+    write(&root.join("aoc/src/registry.rs"), REGISTRY).unwrap();
+    write(&root.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+
+    let written = scaffold(root, 6).unwrap();
+    for file in [
+        "Cargo.toml",
+        "src/lib.rs",
+        "src/main.rs",
+        "benches/day06.rs",
+        "sample.txt",
+    ] {
+        let path = root.join("solutions/day06").join(file);
+        assert!(written.contains(&path));
+        assert!(path.exists());
+    }
+    assert!(read(&root.join("aoc/src/registry.rs"))
+        .unwrap()
+        .contains("day06::Day06,"));
+
+    // existing days are not overwritten
+    let lib = root.join("solutions/day06/src/lib.rs");
+    write(&lib, "// solved").unwrap();
+    assert!(matches!(
+        scaffold(root, 6),
+        Err(ScaffoldError::Exists { day: 6, .. })
+    ));
+    assert_eq!(read(&lib).unwrap(), "// solved");
+    assert!(matches!(
+        scaffold(root, 26),
+        Err(ScaffoldError::InvalidDay(26))
+    ));
+}
@@ -0,0 +1,398 @@
+use crate::{
+    client::{Client, ClientError},
+    runner::Part,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+/// Outcome of submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "outcome")]
+pub enum Outcome {
+    /// Answer is correct.
+    Correct,
+    /// Answer is wrong, and too high.
+    TooHigh,
+    /// Answer is wrong, and too low.
+    TooLow,
+    /// Answer is wrong.
+    Wrong,
+    /// Answer was submitted too soon after the previous one, and was not
+    /// checked.
+    Wait { seconds: u64 },
+    /// This part was already solved.
+    AlreadySolved,
+    /// Response could not be understood, contains the response text.
+    Unknown { response: String },
+}
+
+impl Outcome {
+    /// Parse the outcome from the response page.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if response.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if response.contains("You gave an answer too recently") {
+            Outcome::Wait {
+                seconds: parse_wait(response).map_or(60, |wait| wait.as_secs()),
+            }
+        } else if response.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown {
+                response: response.into(),
+            }
+        }
+    }
+
+    /// Whether this outcome means the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait { seconds } => write!(f, "submitted too soon, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown { .. } => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Parse the time left to wait, from a message such as "You have 1m 23s left
+/// to wait".
+fn parse_wait(response: &str) -> Option<Duration> {
+    let end = response.find(" left to wait")?;
+    let start = response[..end].rfind("You have ")? + "You have ".len();
+    response[start..end]
+        .split_whitespace()
+        .try_fold(0u64, |total, part| {
+            let (number, scale) = [("h", 3600), ("m", 60), ("s", 1)]
+                .into_iter()
+                .find_map(|(unit, scale)| Some((part.strip_suffix(unit)?, scale)))?;
+            let seconds = number.parse::<u64>().ok()?.checked_mul(scale)?;
+            total.checked_add(seconds)
+        })
+        .map(Duration::from_secs)
+}
+
+/// Single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    /// Time of the submission, in seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Error submitting an answer.
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error("day {day} part {part} was already solved with {answer}")]
+    AlreadySolved { day: u8, part: Part, answer: String },
+    #[error("answer {answer} was already submitted and is {outcome}")]
+    KnownWrong { answer: String, outcome: Outcome },
+    #[error("answer {answer} is {bound} than {previous}, which is too {bound}")]
+    OutOfBounds {
+        answer: String,
+        previous: String,
+        bound: &'static str,
+    },
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("accessing history at {path}: {source}")]
+    History { path: PathBuf, source: io::Error },
+    #[error("parsing history at {path}: {source}")]
+    ParseHistory {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Previously submitted answers, stored as JSON.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Load the history, a missing file results in an empty history.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, SubmitError> {
+        let path = path.into();
+        let attempts = match std::fs::read_to_string(&path) {
+            Ok(data) => {
+                serde_json::from_str(&data).map_err(|source| SubmitError::ParseHistory {
+                    path: path.clone(),
+                    source,
+                })?
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => vec![],
+            Err(source) => return Err(SubmitError::History { path, source }),
+        };
+        Ok(History { path, attempts })
+    }
+
+    /// Path of the history file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record an attempt and save the history.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), SubmitError> {
+        self.attempts.push(attempt);
+        let error = |source| SubmitError::History {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(error)?;
+        }
+        let data = serde_json::to_string_pretty(&self.attempts).unwrap();
+        std::fs::write(&self.path, data).map_err(error)
+    }
+
+    /// Check that an answer is worth submitting, based on previous attempts.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<(), SubmitError> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part);
+
+        for attempt in attempts {
+            if attempt.outcome == Outcome::Correct {
+                return Err(SubmitError::AlreadySolved {
+                    day,
+                    part,
+                    answer: attempt.answer.clone(),
+                });
+            }
+
+            if attempt.outcome.is_wrong() && attempt.answer == answer {
+                return Err(SubmitError::KnownWrong {
+                    answer: answer.into(),
+                    outcome: attempt.outcome.clone(),
+                });
+            }
+
+            let (Ok(number), Ok(previous)) =
+                (answer.parse::<i128>(), attempt.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            let bound = match attempt.outcome {
+                Outcome::TooHigh if number >= previous => "high",
+                Outcome::TooLow if number <= previous => "low",
+                _ => continue,
+            };
+            return Err(SubmitError::OutOfBounds {
+                answer: answer.into(),
+                previous: attempt.answer.clone(),
+                bound,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Submit an answer, unless the history shows it is known to be wrong, and
+/// record the attempt.
+pub fn submit(
+    client: &mut Client,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    history.check(day, part, answer)?;
+
+    let level = part.to_string();
+    let response = client.post(
+        &format!("day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let outcome = Outcome::parse(&response);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    history.record(Attempt {
+        day,
+        part,
+        answer: answer.into(),
+        outcome: outcome.clone(),
+        time,
+    })?;
+
+    Ok(outcome)
+}
+
+#[test]
+fn can_parse_outcome() {
+    let page = |text: &str| format!("<html><main><article><p>{text}</p></article></main></html>");
+    assert_eq!(
+        Outcome::parse(&page(
+            "That's the right answer! You are one gold star closer."
+        )),
+        Outcome::Correct
+    );
+    assert_eq!(
+        Outcome::parse(&page(
+            "That's not the right answer; your answer is too high."
+        )),
+        Outcome::TooHigh
+    );
+    assert_eq!(
+        Outcome::parse(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Outcome::TooLow
+    );
+    assert_eq!(
+        Outcome::parse(&page("That's not the right answer. If you're stuck...")),
+        Outcome::Wrong
+    );
+    assert_eq!(
+        Outcome::parse(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have 1m 23s left to wait."
+        )),
+        Outcome::Wait { seconds: 83 }
+    );
+    assert_eq!(
+        Outcome::parse(&page(
+            "You gave an answer too recently. You have 37s left to wait."
+        )),
+        Outcome::Wait { seconds: 37 }
+    );
+    assert_eq!(
+        parse_wait("You have 1h 2m 3s left to wait."),
+        Some(Duration::from_secs(3723))
+    );
+    assert_eq!(parse_wait("You have 5é left to wait."), None);
+    assert_eq!(parse_wait("You have 3s5 left to wait."), None);
+    assert_eq!(
+        parse_wait(&format!("You have {}h left to wait.", u64::MAX / 1000)),
+        None
+    );
+    assert_eq!(
+        parse_wait(&format!("You have {}s 1s left to wait.", u64::MAX)),
+        None
+    );
+    assert_eq!(
+        Outcome::parse(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        )),
+        Outcome::AlreadySolved
+    );
+    assert!(matches!(
+        Outcome::parse("something else"),
+        Outcome::Unknown { .. }
+    ));
+}
+
+#[test]
+fn can_check_history() {
+    let attempt = |part, answer: &str, outcome| Attempt {
+        day: 1,
+        part,
+        answer: answer.into(),
+        outcome,
+        time: 0,
+    };
+    let history = History {
+        path: PathBuf::new(),
+        attempts: vec![
+            attempt(Part::One, "100", Outcome::TooHigh),
+            attempt(Part::One, "50", Outcome::TooLow),
+            attempt(Part::One, "abc", Outcome::Wrong),
+            attempt(Part::One, "60", Outcome::Wait { seconds: 30 }),
+            attempt(Part::Two, "7", Outcome::Correct),
+        ],
+    };
+
+    assert!(history.check(1, Part::One, "75").is_ok());
+    assert!(history.check(1, Part::One, "60").is_ok());
+    assert!(history.check(2, Part::One, "100").is_ok());
+    assert!(matches!(
+        history.check(1, Part::One, "abc"),
+        Err(SubmitError::KnownWrong { .. })
+    ));
+    assert!(matches!(
+        history.check(1, Part::One, "100"),
+        Err(SubmitError::KnownWrong { .. })
+    ));
+    assert!(matches!(
+        history.check(1, Part::One, "120"),
+        Err(SubmitError::OutOfBounds { bound: "high", .. })
+    ));
+    assert!(matches!(
+        history.check(1, Part::One, "3"),
+        Err(SubmitError::OutOfBounds { bound: "low", .. })
+    ));
+    assert!(matches!(
+        history.check(1, Part::Two, "8"),
+        Err(SubmitError::AlreadySolved { .. })
+    ));
+}
+
+#[test]
+fn can_submit() {
+    let (url, requests) = crate::client::serve(vec![
+        (
+            200,
+            "<p>That's not the right answer; your answer is too low.</p>",
+        ),
+        (200, "<p>That's the right answer!</p>"),
+    ]);
+    let mut client = crate::client::local(url);
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("submissions.json");
+    let mut history = History::load(&path).unwrap();
+
+    let outcome = submit(&mut client, &mut history, 5, Part::Two, "42").unwrap();
+    assert_eq!(outcome, Outcome::TooLow);
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("level=2&answer=42"));
+
+    // known wrong answers are refused without a request
+    assert!(matches!(
+        submit(&mut client, &mut history, 5, Part::Two, "42"),
+        Err(SubmitError::KnownWrong { .. })
+    ));
+    assert!(requests.try_recv().is_err());
+
+    let outcome = submit(&mut client, &mut history, 5, Part::Two, "46").unwrap();
+    assert_eq!(outcome, Outcome::Correct);
+
+    // history is persisted
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.attempts.len(), 2);
+    assert_eq!(history.attempts[0].answer, "42");
+    assert_eq!(history.attempts[0].outcome, Outcome::TooLow);
+    assert_eq!(history.attempts[1].outcome, Outcome::Correct);
+}
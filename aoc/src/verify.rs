@@ -0,0 +1,236 @@
+use crate::{
+    registry::{self, Day},
+    runner::{Part, Report},
+    Error,
+};
+use serde::Deserialize;
+use std::{
+    fmt::Display,
+    io,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+/// Known answers, as stored in `answers.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<Expected>,
+}
+
+impl Answers {
+    /// Parse the answers file.
+    pub fn parse(input: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(input)
+    }
+}
+
+/// Expected answer for one part of a day, for one input.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    /// Path of the input file, relative to the answers file.
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+/// Outcome of checking one expected answer.
+#[derive(Debug)]
+pub enum Outcome {
+    /// Solution produced the expected answer.
+    Pass,
+    /// Input file does not exist, so the answer was not checked.
+    Skipped,
+    /// Solution produced a different answer, `None` if it is unsolved.
+    Wrong(Option<String>),
+    /// Solution returned an error.
+    Error(Error),
+    /// Solution panicked.
+    Panic(String),
+}
+
+impl Outcome {
+    /// Whether this outcome means the solution is broken.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Pass | Outcome::Skipped)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Skipped => write!(f, "skipped, missing input"),
+            Outcome::Wrong(Some(answer)) => write!(f, "FAILED, got {answer}"),
+            Outcome::Wrong(None) => write!(f, "FAILED, unsolved"),
+            Outcome::Error(error) => write!(f, "FAILED, {error}"),
+            Outcome::Panic(message) => write!(f, "FAILED, panicked: {message}"),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+/// Check a single expected answer, resolving the input relative to `root`.
+pub fn check(expected: &Expected, root: &Path) -> Outcome {
+    let input = match std::fs::read_to_string(root.join(&expected.input)) {
+        Ok(input) => input,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Outcome::Skipped,
+        Err(error) => return Outcome::Error(Error::Input(error)),
+    };
+
+    match registry::get(expected.day) {
+        Ok(day) => run(day, expected, &input),
+        Err(error) => Outcome::Error(error),
+    }
+}
+
+/// Run the day on the input and compare its answer with the expected one.
+fn run(day: &Day, expected: &Expected, input: &str) -> Outcome {
+    let result = catch_unwind(AssertUnwindSafe(|| day.run(input, &[expected.part])));
+    let report: Report = match result {
+        Ok(Ok(report)) => report,
+        Ok(Err(error)) => return Outcome::Error(error),
+        Err(payload) => return Outcome::Panic(panic_message(payload)),
+    };
+
+    let answer = report
+        .part(expected.part)
+        .and_then(|part| part.answer.clone());
+    match answer {
+        Some(answer) if answer == expected.answer => Outcome::Pass,
+        other => Outcome::Wrong(other),
+    }
+}
+
+/// Check all expected answers.
+pub fn verify<'a>(answers: &'a Answers, root: &Path) -> Vec<(&'a Expected, Outcome)> {
+    answers
+        .answers
+        .iter()
+        .map(|expected| (expected, check(expected, root)))
+        .collect()
+}
+
+#[test]
+fn answers_are_correct() {
+    let answers = Answers::parse(include_str!("../../answers.toml")).unwrap();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for (expected, outcome) in verify(&answers, &root) {
+        assert!(
+            matches!(outcome, Outcome::Pass),
+            "day {} part {} on {}: {outcome}",
+            expected.day,
+            expected.part,
+            expected.input.display()
+        );
+    }
+}
+
+#[test]
+fn can_detect_failures() {
+    let answers = Answers::parse(
+        r#"
+        [[answer]]
+        day = 9
+        part = 1
+        input = "solutions/day09/sample.txt"
+        answer = "115"
+
+        [[answer]]
+        day = 4
+        part = 2
+        input = "solutions/day04/src/input.txt"
+        answer = "30"
+
+        [[answer]]
+        day = 9
+        part = 2
+        input = "missing.txt"
+        answer = "2"
+
+        [[answer]]
+        day = 8
+        part = 1
+        input = "solutions/day09/sample.txt"
+        answer = "2"
+
+        [[answer]]
+        day = 25
+        part = 1
+        input = "solutions/day09/sample.txt"
+        answer = "2"
+
+        [[answer]]
+        day = 9
+        part = 1
+        input = "solutions/day09"
+        answer = "114"
+        "#,
+    )
+    .unwrap();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let outcomes: Vec<_> = verify(&answers, &root)
+        .into_iter()
+        .map(|(_, outcome)| outcome)
+        .collect();
+    assert!(matches!(&outcomes[0], Outcome::Wrong(Some(answer)) if answer == "114"));
+    assert!(matches!(outcomes[1], Outcome::Wrong(None)));
+    assert!(matches!(outcomes[2], Outcome::Skipped));
+    assert!(matches!(
+        outcomes[3],
+        Outcome::Error(Error::Solve { day: 8, .. })
+    ));
+    assert!(matches!(outcomes[4], Outcome::Error(Error::UnknownDay(25))));
+    // a directory cannot be read as input, which is not the same as missing
+    assert!(matches!(outcomes[5], Outcome::Error(Error::Input(_))));
+    assert!(outcomes[5].is_failure());
+    assert!(outcomes[0].is_failure());
+    assert!(!outcomes[2].is_failure());
+}
+
+#[test]
+fn can_catch_panics() {
+    use aoc_common::{Solution, Unsolved};
+    use std::convert::Infallible;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 1;
+        type Input = ();
+        type Error = Infallible;
+        type Part1 = u64;
+        type Part2 = Unsolved;
+
+        fn parse(_input: &str) -> Result<Self::Input, Self::Error> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+            panic!("broken solution")
+        }
+
+        fn part2(_input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+            Ok(Unsolved)
+        }
+    }
+
+    let expected = Expected {
+        day: 1,
+        part: Part::One,
+        input: "input.txt".into(),
+        answer: "2".into(),
+    };
+    let outcome = run(&Day::new::<Panics>(), &expected, "");
+    assert!(matches!(outcome, Outcome::Panic(message) if message == "broken solution"));
+}
@@ -0,0 +1,29 @@
+use std::num::ParseIntError;
+use thiserror::Error;
+
+/// Error parsing puzzle input.
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("missing {0}")]
+    Missing(&'static str),
+    #[error("invalid {what}: {value:?}")]
+    Invalid { what: &'static str, value: String },
+    #[error("row {row} has length {length}, expected {expected}")]
+    RaggedGrid {
+        row: usize,
+        length: usize,
+        expected: usize,
+    },
+    #[error(transparent)]
+    ParseInt(#[from] ParseIntError),
+}
+
+impl ParseError {
+    /// Create an [`Invalid`](ParseError::Invalid) error for the given value.
+    pub fn invalid(what: &'static str, value: impl Into<String>) -> Self {
+        ParseError::Invalid {
+            what,
+            value: value.into(),
+        }
+    }
+}
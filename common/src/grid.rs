@@ -0,0 +1,156 @@
+use crate::ParseError;
+
+/// Point as (x, y)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Default)]
+pub struct Point<T = usize>(pub T, pub T);
+
+impl Point {
+    /// Iterate over the (up to eight) points touching this one, including
+    /// diagonals.
+    pub fn touching(&self) -> impl Iterator<Item = Point> {
+        let offsets = [0isize, 1, 1, 1, 0, -1, -1, -1];
+
+        let point = *self;
+        offsets
+            .into_iter()
+            .zip(offsets.into_iter().cycle().skip(6))
+            .flat_map(move |(x, y)| {
+                let x = point.0.checked_add_signed(x)?;
+                let y = point.1.checked_add_signed(y)?;
+                Some(Point(x, y))
+            })
+    }
+}
+
+/// Dense two-dimensional grid, stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a grid from lines of text, mapping every character to a cell.
+    ///
+    /// All lines must have the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: Vec::with_capacity(input.len()),
+        };
+
+        for (row, line) in input.lines().enumerate() {
+            let before = grid.cells.len();
+            grid.cells.extend(line.chars().map(&mut cell));
+            let length = grid.cells.len() - before;
+
+            if row == 0 {
+                grid.width = length;
+            } else if length != grid.width {
+                return Err(ParseError::RaggedGrid {
+                    row,
+                    length,
+                    expected: grid.width,
+                });
+            }
+
+            grid.height += 1;
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        (point.0 < self.width && point.1 < self.height).then(|| point.1 * self.width + point.0)
+    }
+
+    /// Get the cell at the given point, if it is inside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    /// Get the cell at the given point mutably, if it is inside the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Iterate over all cells along with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| (Point(offset % width, offset / width), cell))
+    }
+
+    /// Iterate over the rows of the grid.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+#[test]
+fn can_touch() {
+    let touching: Vec<_> = Point(1, 1).touching().collect();
+    assert_eq!(touching.len(), 8);
+    for point in [
+        Point(0, 0),
+        Point(1, 0),
+        Point(2, 0),
+        Point(0, 1),
+        Point(2, 1),
+        Point(0, 2),
+        Point(1, 2),
+        Point(2, 2),
+    ] {
+        assert!(touching.contains(&point));
+    }
+
+    let touching: Vec<_> = Point(0, 0).touching().collect();
+    assert_eq!(touching.len(), 3);
+}
+
+#[test]
+fn can_parse_grid() {
+    let grid = Grid::parse("#..\n.#.\n", |c| c == '#').unwrap();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.get(Point(0, 0)), Some(&true));
+    assert_eq!(grid.get(Point(1, 0)), Some(&false));
+    assert_eq!(grid.get(Point(1, 1)), Some(&true));
+    assert_eq!(grid.get(Point(3, 0)), None);
+    assert_eq!(grid.get(Point(0, 2)), None);
+    assert_eq!(grid.iter().filter(|(_, cell)| **cell).count(), 2);
+    assert_eq!(grid.rows().count(), 2);
+
+    assert!(matches!(
+        Grid::parse("...\n..\n", |c| c),
+        Err(ParseError::RaggedGrid {
+            row: 1,
+            length: 2,
+            expected: 3
+        })
+    ));
+}
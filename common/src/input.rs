@@ -0,0 +1,19 @@
+use std::{
+    fs::read_to_string as read_file,
+    io::{read_to_string, stdin, Result},
+    path::Path,
+};
+
+/// Read the whole puzzle input from standard input.
+pub fn read_stdin() -> Result<String> {
+    read_to_string(stdin())
+}
+
+/// Read the puzzle input from a file, or from standard input if there is no
+/// path or the path is `-`.
+pub fn read(path: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) if path != Path::new("-") => read_file(path),
+        _ => read_stdin(),
+    }
+}
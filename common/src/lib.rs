@@ -0,0 +1,16 @@
+//! Shared plumbing for the Advent of Code solutions.
+//!
+//! Every day crate needs to read its input, split it into lines or blocks and
+//! parse the pieces. This crate contains those helpers, along with a 2D
+//! [`Point`] and [`Grid`] for the puzzles that are laid out on a map, and the
+//! [`Solution`] trait which every day implements.
+
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod solution;
+pub mod split;
+
+pub use error::ParseError;
+pub use grid::{Grid, Point};
+pub use solution::{Answer, Solution, Unsolved};
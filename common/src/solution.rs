@@ -0,0 +1,122 @@
+use crate::input::read_stdin;
+use std::{error::Error, fmt::Display, process::exit};
+
+/// Answer to one part of a puzzle.
+pub trait Answer: Display {
+    /// Whether answers of this type are solutions, known without an input.
+    const SOLVED: bool = true;
+
+    /// Whether this part has been solved.
+    fn is_solved(&self) -> bool {
+        Self::SOLVED
+    }
+}
+
+macro_rules! impl_answer {
+    ($($ty:ty),*) => {
+        $(impl Answer for $ty {})*
+    };
+}
+
+impl_answer!(u32, u64, usize, i32, i64, String);
+
+/// Placeholder answer for parts which have not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+impl Answer for Unsolved {
+    const SOLVED: bool = false;
+}
+
+/// Solution to a single day of the puzzle.
+///
+/// The input is parsed once, and the parsed input is shared by both parts. This
+/// allows measuring the parse stage separately from the parts.
+pub trait Solution {
+    /// Day of the puzzle, starting at 1.
+    const DAY: u8;
+
+    /// Parsed puzzle input.
+    type Input;
+
+    /// Error parsing or solving the puzzle.
+    type Error: Error + Send + Sync + 'static;
+
+    /// Answer for part one.
+    type Part1: Answer;
+
+    /// Answer for part two, use [`Unsolved`] if there is none yet.
+    type Part2: Answer;
+
+    /// Parse the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    /// Solve part one of the puzzle.
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error>;
+
+    /// Solve part two of the puzzle.
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Self::Error>;
+}
+
+/// Solve both parts of the puzzle for the given input.
+pub fn solve<S: Solution>(input: &str) -> Result<(S::Part1, S::Part2), S::Error> {
+    let input = S::parse(input)?;
+    Ok((S::part1(&input)?, S::part2(&input)?))
+}
+
+/// Read the input from standard input and print the answers to both parts.
+///
+/// This is meant to be used as the `main` function of the day crates.
+pub fn main<S: Solution>() {
+    let input = read_stdin().unwrap();
+    match solve::<S>(&input) {
+        Ok((part1, part2)) => {
+            println!("part1 {part1}");
+            println!("part2 {part2}");
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            exit(1);
+        }
+    }
+}
+
+#[test]
+fn can_solve() {
+    use std::num::ParseIntError;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Input = Vec<u64>;
+        type Error = ParseIntError;
+        type Part1 = u64;
+        type Part2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            crate::split::numbers(input)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(_input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+            Ok(Unsolved)
+        }
+    }
+
+    let (part1, part2) = solve::<Sum>("1 2 3").unwrap();
+    assert_eq!(part1, 6);
+    assert!(part1.is_solved());
+    assert!(!part2.is_solved());
+    assert_eq!(part2.to_string(), "unsolved");
+    assert!(solve::<Sum>("1 x").is_err());
+}
@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+/// Split input into blocks separated by blank lines.
+///
+/// Handles both `\n` and `\r\n` line endings, and ignores leading and trailing
+/// blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input.trim_matches(['\r', '\n']);
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end_matches(['\r', '\n']).is_empty() {
+                let block = rest[..offset].trim_end_matches(['\r', '\n']);
+                rest = rest[offset..].trim_start_matches(['\r', '\n']);
+                return Some(block);
+            }
+            offset += line.len();
+        }
+
+        Some(std::mem::take(&mut rest))
+    })
+}
+
+/// Parse every line of the input.
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, T::Err> {
+    input.lines().map(str::parse).collect()
+}
+
+/// Parse whitespace-separated values, such as a list of numbers.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>, T::Err> {
+    input.split_whitespace().map(str::parse).collect()
+}
+
+#[test]
+fn can_split_blocks() {
+    let found: Vec<_> = blocks("a\nb\n\nc\n\n\nd\n").collect();
+    assert_eq!(found, ["a\nb", "c", "d"]);
+
+    let found: Vec<_> = blocks("a\r\nb\r\n\r\nc\r\n").collect();
+    assert_eq!(found, ["a\r\nb", "c"]);
+
+    assert_eq!(blocks("").count(), 0);
+}
+
+#[test]
+fn can_parse_numbers() {
+    assert_eq!(
+        numbers::<u64>(" 41 48  83 86 17 "),
+        Ok(vec![41, 48, 83, 86, 17])
+    );
+    assert_eq!(numbers::<i64>("0 -3 6"), Ok(vec![0, -3, 6]));
+    assert!(numbers::<u64>("1 x 3").is_err());
+}
+
+#[test]
+fn can_parse_lines() {
+    assert_eq!(parse_lines::<u64>("1\n2\n3\n"), Ok(vec![1, 2, 3]));
+}
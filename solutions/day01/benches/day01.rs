@@ -0,0 +1,96 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day01::{
+    calibrate, calibrate_bytes, solve, solve2, solve2_reference, stream, Day01, Digits, Scanner,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::hint::black_box;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Generate lines of random letters, digits and spelled-out digits.
+fn generate(lines: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut output = String::new();
+    for _ in 0..lines {
+        let mut line = String::new();
+        line.push(char::from(b'1' + rng.gen_range(0..9)));
+        for _ in 0..rng.gen_range(5..40) {
+            match rng.gen_range(0..10) {
+                0 => line.push(char::from(b'1' + rng.gen_range(0..9))),
+                1 => line.push_str(WORDS.choose(&mut rng).unwrap()),
+                _ => line.push(char::from(b'a' + rng.gen_range(0..26))),
+            }
+        }
+        output.push_str(&line);
+        output.push('\n');
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    // the second sample has lines without numeric digits, so part 1 is not
+    // benchmarked on it
+    let inputs = [
+        ("sample1", include_str!("../sample1.txt").to_string(), true),
+        ("sample2", include_str!("../sample2.txt").to_string(), false),
+        ("generated", generate(10_000), true),
+    ];
+
+    for (name, input, part1) in &inputs {
+        let mut group = c.benchmark_group(format!("day01/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day01::parse(input).unwrap();
+        group.bench_function("parse", |b| b.iter(|| Day01::parse(black_box(input))));
+        if *part1 {
+            group.bench_function("part1", |b| b.iter(|| Day01::part1(black_box(&parsed))));
+        }
+        group.bench_function("part2", |b| b.iter(|| Day01::part2(black_box(&parsed))));
+        group.finish();
+    }
+
+    // both parts at once, on strings and on raw bytes
+    let input = generate(100_000);
+    let part1 = Scanner::digits(Digits::Ascii);
+    let scanners = [&part1, Scanner::english()];
+    let mut group = c.benchmark_group("day01/both");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("calibrate", |b| {
+        b.iter(|| {
+            (
+                calibrate(black_box(&input), solve),
+                calibrate(&input, solve2),
+            )
+        })
+    });
+    group.bench_function("calibrate_bytes", |b| {
+        b.iter(|| calibrate_bytes(black_box(input.as_bytes()), scanners))
+    });
+    group.bench_function("stream", |b| {
+        b.iter(|| stream(black_box(input.as_bytes()), scanners))
+    });
+    group.finish();
+
+    // a short line, and a long line with a single digit in the middle
+    let lines = [
+        ("short", "xtwone3fourseveneightwothreenine".to_string()),
+        (
+            "long",
+            "abcdxyzqwe".repeat(500) + "1" + &"abcdxyzqwe".repeat(500),
+        ),
+    ];
+    for (name, line) in &lines {
+        let mut group = c.benchmark_group(format!("day01/solve2/{name}"));
+        group.throughput(Throughput::Bytes(line.len() as u64));
+        group.bench_function("scanner", |b| b.iter(|| solve2(black_box(line))));
+        group.bench_function("reference", |b| {
+            b.iter(|| solve2_reference(black_box(line)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
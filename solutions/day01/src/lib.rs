@@ -0,0 +1,1000 @@
+use aoc_common::Solution;
+use memchr::memchr_iter;
+use std::{
+    cmp::Reverse,
+    collections::VecDeque,
+    fmt::Display,
+    io::{self, Read},
+    iter::once,
+    ops::Range,
+    str::FromStr,
+    sync::OnceLock,
+};
+use thiserror::Error;
+
+/// First code point of every run of ten decimal digits (general category `Nd`)
+/// in Unicode 16.0, in order. Every digit has the offset from its zero as value.
+#[rustfmt::skip]
+const DECIMAL_ZEROS: [u32; 76] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6,
+    0x0B66, 0x0BE6, 0x0C66, 0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0,
+    0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900,
+    0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x10D30, 0x10D40,
+    0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50,
+    0x11D50, 0x11DA0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70,
+    0x1CCF0, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0,
+    0x1E4F0, 0x1E5F1, 0x1E950, 0x1FBF0,
+];
+
+/// Value of a decimal digit of any script, such as `٣` or `３`.
+pub fn unicode_digit(c: char) -> Option<u32> {
+    let c = c as u32;
+    let index = DECIMAL_ZEROS
+        .partition_point(|&zero| zero <= c)
+        .checked_sub(1)?;
+    let value = c - DECIMAL_ZEROS[index];
+    (value < 10).then_some(value)
+}
+
+/// Characters recognised as numeric digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Digits {
+    /// ASCII digits only, as in the puzzle.
+    #[default]
+    Ascii,
+    /// Decimal digits of any script.
+    Unicode,
+}
+
+impl Digits {
+    /// Value of the character, if it is a digit.
+    pub fn value(self, c: char) -> Option<u32> {
+        match self {
+            Digits::Ascii => c.to_digit(10),
+            Digits::Unicode => unicode_digit(c),
+        }
+    }
+
+    /// All digits and their values.
+    pub fn chars(self) -> impl Iterator<Item = (char, u32)> {
+        let zeros = match self {
+            Digits::Ascii => &DECIMAL_ZEROS[..1],
+            Digits::Unicode => &DECIMAL_ZEROS[..],
+        };
+        zeros.iter().flat_map(|zero| {
+            (0..10).flat_map(move |value| Some((char::from_u32(zero + value)?, value)))
+        })
+    }
+}
+
+/// Line without any digit.
+///
+/// The functions solving a single line report it as line 1, [`calibrate`]
+/// numbers the lines of a document.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line} has no digit: {content:?}")]
+pub struct NoDigit {
+    pub line: usize,
+    pub content: String,
+}
+
+impl NoDigit {
+    fn new(content: &str) -> Self {
+        NoDigit {
+            line: 1,
+            content: content.into(),
+        }
+    }
+}
+
+/// Lines without any digit in a calibration document.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationError(pub Vec<NoDigit>);
+
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0[..] {
+            [error] => write!(f, "{error}"),
+            errors => {
+                let lines: Vec<String> = errors.iter().map(|e| e.line.to_string()).collect();
+                write!(f, "lines {} have no digit", lines.join(", "))
+            }
+        }
+    }
+}
+
+/// Sum of the calibration values of a document, and the lines which have none.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    /// Sum of the values of the valid lines.
+    pub sum: u64,
+    /// Lines without a digit, which are skipped.
+    pub errors: Vec<NoDigit>,
+}
+
+impl Calibration {
+    /// Sum of the values, failing if any line has no digit.
+    pub fn result(self) -> Result<u64, CalibrationError> {
+        match self.errors.is_empty() {
+            true => Ok(self.sum),
+            false => Err(CalibrationError(self.errors)),
+        }
+    }
+}
+
+/// Solve every line of a document, collecting the lines which fail.
+pub fn calibrate(document: &str, solve: impl Fn(&str) -> Result<u32, NoDigit>) -> Calibration {
+    let mut calibration = Calibration::default();
+    for (line, content) in (1..).zip(document.lines()) {
+        match solve(content) {
+            Ok(value) => calibration.sum += u64::from(value),
+            Err(error) => calibration.errors.push(NoDigit { line, ..error }),
+        }
+    }
+    calibration
+}
+
+/// Find the first and last of the given digits in a string.
+pub fn find_digits(input: &str, digits: Digits) -> Option<(Match, Match)> {
+    let mut numbers = input.char_indices().flat_map(|(index, c)| {
+        Some(Match {
+            range: index..index + c.len_utf8(),
+            value: digits.value(c)?,
+        })
+    });
+    let first = numbers.next()?;
+    let last = numbers.last().unwrap_or_else(|| first.clone());
+    Some((first, last))
+}
+
+/// Find the first and last digit in a string, and turn them into a number.
+pub fn solve(input: &str) -> Result<u32, NoDigit> {
+    solve_digits(input, Digits::Ascii)
+}
+
+/// Find the first and last digit in a string, recognising the given digits.
+pub fn solve_digits(input: &str, digits: Digits) -> Result<u32, NoDigit> {
+    let (first, last) = find_digits(input, digits).ok_or_else(|| NoDigit::new(input))?;
+    Ok(10 * first.value + last.value)
+}
+
+fn prefixes(s: &str) -> impl DoubleEndedIterator<Item = &str> {
+    s.char_indices()
+        .map(move |(pos, _)| &s[..pos])
+        .chain(once(s))
+}
+
+fn suffixes(s: &str) -> impl DoubleEndedIterator<Item = &str> {
+    s.char_indices()
+        .map(move |(pos, _)| &s[pos..])
+        .chain(once(""))
+        .rev()
+}
+
+/// Number of transitions of every state of an [`Automaton`], one per byte.
+const ALPHABET: usize = 256;
+
+/// Length and value of a pattern.
+type Output = Option<(usize, u32)>;
+
+/// Pick the longest of two patterns, or the one with the smaller value.
+fn longest(a: Output, b: Output) -> Output {
+    match (a, b) {
+        (Some((a, x)), Some((b, y))) if a == b => Some((a, x.min(y))),
+        (a, b) => a.max(b),
+    }
+}
+
+/// Aho-Corasick automaton finding the leftmost of a set of patterns.
+#[derive(Debug, Clone)]
+struct Automaton {
+    /// Dense transition table, with one entry per byte for every state. State
+    /// 0 is the root.
+    next: Vec<[u32; ALPHABET]>,
+    /// Longest pattern ending in every state, which is the one starting first.
+    outputs: Vec<Output>,
+    /// Length of the longest pattern.
+    longest: usize,
+}
+
+impl Automaton {
+    fn new<P: IntoIterator<Item = u8>>(patterns: impl IntoIterator<Item = (P, u32)>) -> Self {
+        let mut next = vec![[0; ALPHABET]];
+        let mut outputs = vec![None];
+        let mut max = 0;
+        for (pattern, value) in patterns {
+            let mut state = 0;
+            let mut length = 0;
+            for byte in pattern {
+                if next[state][byte as usize] == 0 {
+                    next[state][byte as usize] = outputs.len() as u32;
+                    next.push([0; ALPHABET]);
+                    outputs.push(None);
+                }
+                state = next[state][byte as usize] as usize;
+                length += 1;
+            }
+            if length > 0 {
+                outputs[state] = longest(outputs[state], Some((length, value)));
+                max = max.max(length);
+            }
+        }
+
+        // turn the trie into an automaton in breadth-first order, so that the
+        // failure state of every state is complete before the state itself
+        let mut fail = vec![0; outputs.len()];
+        let mut queue: VecDeque<usize> = next[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            outputs[state] = longest(outputs[state], outputs[fail[state]]);
+            let fallbacks = next[fail[state]];
+            for (target, fallback) in next[state].iter_mut().zip(fallbacks) {
+                match *target as usize {
+                    0 => *target = fallback,
+                    child => {
+                        fail[child] = fallback as usize;
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+
+        Automaton {
+            next,
+            outputs,
+            longest: max,
+        }
+    }
+
+    /// Find the pattern starting first. If several start at the same position,
+    /// the longest one wins, and then the one with the smallest value. Stops as
+    /// soon as no pattern can start before the one found.
+    fn find(&self, input: impl Iterator<Item = u8>) -> Option<Match> {
+        let mut state = 0;
+        let mut found: Option<(usize, Reverse<usize>, u32)> = None;
+        for (end, byte) in (1..).zip(input) {
+            if found.is_some_and(|(start, _, _)| end > start + self.longest) {
+                break;
+            }
+            state = self.next[state][byte as usize] as usize;
+            if let Some((length, value)) = self.outputs[state] {
+                let key = (end - length, Reverse(length), value);
+                if found.is_none_or(|found| key < found) {
+                    found = Some(key);
+                }
+            }
+        }
+        found.map(|(start, Reverse(length), value)| Match {
+            range: start..start + length,
+            value,
+        })
+    }
+}
+
+/// Scanner finding the first and last digit in linear time, where digits are
+/// given by patterns which may overlap (such as in "eightwo").
+///
+/// The first digit is found by scanning forwards, and the last by scanning
+/// backwards with the reversed patterns. Both scans stop at the first match,
+/// so together they read every byte at most once (plus the length of a
+/// pattern where they meet).
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl Scanner {
+    /// Create a scanner for the given patterns and their values, which are
+    /// digits. Empty patterns are ignored.
+    pub(crate) fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let patterns: Vec<(&str, u32)> = patterns.into_iter().collect();
+        Scanner {
+            forward: Automaton::new(patterns.iter().map(|(p, v)| (p.bytes(), *v))),
+            backward: Automaton::new(patterns.iter().map(|(p, v)| (p.bytes().rev(), *v))),
+        }
+    }
+
+    /// Scanner for the given digits only, without any words.
+    pub fn digits(digits: Digits) -> Self {
+        Vocabulary { words: vec![] }.scanner_with(digits)
+    }
+
+    /// Scanner for the digits and the [`Vocabulary::english`] words.
+    pub fn english() -> &'static Self {
+        static ENGLISH: OnceLock<Scanner> = OnceLock::new();
+        ENGLISH.get_or_init(|| Vocabulary::english().scanner())
+    }
+
+    /// Find the values of the first and last digit in a string.
+    ///
+    /// The first digit is the one starting first, the last digit the one ending
+    /// last. When several patterns start (or end) at the same position, the
+    /// longest one wins, and then the one with the smallest value.
+    pub fn scan(&self, input: &str) -> Option<(u32, u32)> {
+        self.scan_bytes(input.as_bytes())
+    }
+
+    /// Find the values of the first and last digit in raw bytes, see
+    /// [`Scanner::scan`].
+    pub fn scan_bytes(&self, input: &[u8]) -> Option<(u32, u32)> {
+        let first = self.forward.find(input.iter().copied())?;
+        let last = self.backward.find(input.iter().rev().copied())?;
+        Some((first.value, last.value))
+    }
+
+    /// Find the first and last digit in a string, see [`Scanner::scan`].
+    pub fn find(&self, input: &str) -> Option<(Match, Match)> {
+        let first = self.forward.find(input.bytes())?;
+        let last = self.backward.find(input.bytes().rev())?;
+        let last = Match {
+            range: input.len() - last.range.end..input.len() - last.range.start,
+            value: last.value,
+        };
+        Some((first, last))
+    }
+}
+
+/// Digit found in a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Byte range of the digit, or of the word for it.
+    pub range: Range<usize>,
+    /// Value of the digit.
+    pub value: u32,
+}
+
+/// Error parsing a [`Vocabulary`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum VocabularyError {
+    #[error("line {line}: expected a word and its value, got {content:?}")]
+    Invalid { line: usize, content: String },
+    #[error("value {value} of {word:?} must be a digit from 0 to 9")]
+    Digit { word: String, value: u32 },
+}
+
+/// Words for the digits, mapping each word to its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Create a vocabulary from words and their values, which must be digits.
+    pub fn new<W: Into<String>>(
+        words: impl IntoIterator<Item = (W, u32)>,
+    ) -> Result<Self, VocabularyError> {
+        let words = words
+            .into_iter()
+            .map(|(word, value)| {
+                let word = word.into();
+                match value {
+                    0..=9 => Ok((word, value)),
+                    _ => Err(VocabularyError::Digit { word, value }),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Vocabulary { words })
+    }
+
+    fn numbered(words: [&str; 9]) -> Self {
+        Vocabulary {
+            words: words.into_iter().map(String::from).zip(1..).collect(),
+        }
+    }
+
+    /// English words for one to nine, as used by the puzzle.
+    pub fn english() -> Self {
+        Self::numbered([
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ])
+    }
+
+    /// English words for zero to nine.
+    pub fn english_zero() -> Self {
+        let mut vocabulary = Self::english();
+        vocabulary.words.push(("zero".into(), 0));
+        vocabulary
+    }
+
+    /// German words for one to nine.
+    pub fn german() -> Self {
+        Self::numbered([
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ])
+    }
+
+    /// French words for one to nine.
+    pub fn french() -> Self {
+        Self::numbered([
+            "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ])
+    }
+
+    /// Roman numerals for one to nine. Where numerals overlap, the longest
+    /// one wins, so `VIII` is eight rather than five.
+    pub fn roman() -> Self {
+        Self::numbered(["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"])
+    }
+
+    /// Add a word to the vocabulary, its value must be a digit.
+    pub fn with(self, word: impl Into<String>, value: u32) -> Result<Self, VocabularyError> {
+        Vocabulary::new(self.words.into_iter().chain([(word.into(), value)]))
+    }
+
+    /// Words and their values.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// Scanner for the words and the ASCII digits.
+    pub fn scanner(&self) -> Scanner {
+        self.scanner_with(Digits::Ascii)
+    }
+
+    /// Scanner for the words and the given digits.
+    pub fn scanner_with(&self, digits: Digits) -> Scanner {
+        let digits: Vec<(String, u32)> = digits
+            .chars()
+            .map(|(c, value)| (c.to_string(), value))
+            .collect();
+        let digits = digits.iter().map(|(c, value)| (c.as_str(), *value));
+        Scanner::new(self.words().chain(digits))
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = VocabularyError;
+
+    /// Parse a vocabulary with a word and its value on every line, separated by
+    /// whitespace. Empty lines and lines starting with `#` are ignored.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words = vec![];
+        for (line, content) in (1..).zip(input.lines()) {
+            let content = content.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+            let invalid = || VocabularyError::Invalid {
+                line,
+                content: content.into(),
+            };
+            let (word, value) = content
+                .split_once(char::is_whitespace)
+                .ok_or_else(invalid)?;
+            let value: u32 = value.trim().parse().map_err(|_| invalid())?;
+            words.push((word, value));
+        }
+        Vocabulary::new(words)
+    }
+}
+
+/// Find the first and last digit in a string, where digits may also be written
+/// out with the words the scanner was built for.
+pub fn solve_with(scanner: &Scanner, input: &str) -> Result<u32, NoDigit> {
+    let (first, last) = scanner.scan(input).ok_or_else(|| NoDigit::new(input))?;
+    Ok(10 * first + last)
+}
+
+/// Find the first and last digit in a string, but the digit may also be written
+/// out as text.
+pub fn solve2(input: &str) -> Result<u32, NoDigit> {
+    solve_with(Scanner::english(), input)
+}
+
+/// Reference implementation of [`solve2`], which tests every pattern against
+/// every prefix and suffix of the string.
+pub fn solve2_reference(input: &str) -> Result<u32, NoDigit> {
+    let digits: [&[&'static str]; 10] = [
+        &["0"],
+        &["one", "1"],
+        &["two", "2"],
+        &["three", "3"],
+        &["four", "4"],
+        &["five", "5"],
+        &["six", "6"],
+        &["seven", "7"],
+        &["eight", "8"],
+        &["nine", "9"],
+    ];
+
+    let first = suffixes(input)
+        .rev()
+        .flat_map(|prefix| {
+            digits
+                .iter()
+                .enumerate()
+                .find(|(_, patterns)| patterns.iter().any(|pattern| prefix.starts_with(pattern)))
+                .map(|(index, _)| index)
+        })
+        .next()
+        .ok_or_else(|| NoDigit::new(input))?;
+
+    let last = prefixes(input)
+        .rev()
+        .flat_map(|prefix| {
+            digits
+                .iter()
+                .enumerate()
+                .find(|(_, patterns)| patterns.iter().any(|pattern| prefix.ends_with(pattern)))
+                .map(|(index, _)| index)
+        })
+        .next()
+        .ok_or_else(|| NoDigit::new(input))?;
+
+    Ok((10 * first + last) as u32)
+}
+
+/// Size of the buffer [`stream`] reads into, which grows for longer lines.
+const BUFFER: usize = 64 * 1024;
+
+/// Calibrations of a document with several scanners, fed one line at a time.
+struct Calibrations<'a, const N: usize> {
+    scanners: [&'a Scanner; N],
+    calibrations: [Calibration; N],
+    line: usize,
+}
+
+impl<'a, const N: usize> Calibrations<'a, N> {
+    fn new(scanners: [&'a Scanner; N]) -> Self {
+        Calibrations {
+            scanners,
+            calibrations: std::array::from_fn(|_| Calibration::default()),
+            line: 0,
+        }
+    }
+
+    /// Add a line, without the newline.
+    fn line(&mut self, content: &[u8]) {
+        self.line += 1;
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+        for (scanner, calibration) in self.scanners.iter().zip(&mut self.calibrations) {
+            match scanner.scan_bytes(content) {
+                Some((first, last)) => calibration.sum += u64::from(10 * first + last),
+                None => calibration.errors.push(NoDigit {
+                    line: self.line,
+                    content: String::from_utf8_lossy(content).into(),
+                }),
+            }
+        }
+    }
+
+    /// Add all complete lines, returning the offset of the rest.
+    fn lines(&mut self, buffer: &[u8]) -> usize {
+        let mut start = 0;
+        for end in memchr_iter(b'\n', buffer) {
+            self.line(&buffer[start..end]);
+            start = end + 1;
+        }
+        start
+    }
+}
+
+/// Solve every line of a document with each of the scanners, working directly
+/// on the bytes. Lines are split on `\n`, and a trailing `\r` is dropped.
+pub fn calibrate_bytes<const N: usize>(
+    document: &[u8],
+    scanners: [&Scanner; N],
+) -> [Calibration; N] {
+    let mut calibrations = Calibrations::new(scanners);
+    let rest = calibrations.lines(document);
+    if rest < document.len() {
+        calibrations.line(&document[rest..]);
+    }
+    calibrations.calibrations
+}
+
+/// Solve every line read from the reader with each of the scanners, like
+/// [`calibrate_bytes`]. Reads into a single buffer, so memory use does not
+/// depend on the size of the input, only on the longest line.
+pub fn stream<const N: usize>(
+    reader: impl Read,
+    scanners: [&Scanner; N],
+) -> io::Result<[Calibration; N]> {
+    stream_with(reader, scanners, BUFFER)
+}
+
+fn stream_with<const N: usize>(
+    mut reader: impl Read,
+    scanners: [&Scanner; N],
+    capacity: usize,
+) -> io::Result<[Calibration; N]> {
+    let mut calibrations = Calibrations::new(scanners);
+    let mut buffer = vec![0; capacity.max(1)];
+    let mut filled = 0;
+    loop {
+        if filled == buffer.len() {
+            buffer.resize(2 * buffer.len(), 0);
+        }
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+        let rest = calibrations.lines(&buffer[..filled]);
+        buffer.copy_within(rest..filled, 0);
+        filled -= rest;
+    }
+    if filled > 0 {
+        calibrations.line(&buffer[..filled]);
+    }
+    Ok(calibrations.calibrations)
+}
+
+/// How [`highlight`] marks the first and last digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// First digit in `[]`, last digit in `{}`.
+    Brackets,
+    /// First digit in green, last digit in blue, and cyan where they overlap.
+    Ansi,
+}
+
+/// Mark the first and last digit in a line, which may overlap.
+pub fn highlight(line: &str, first: &Range<usize>, last: &Range<usize>, style: Style) -> String {
+    let mut output = String::new();
+    let mut previous = (false, false);
+    for (index, c) in line.char_indices().chain([(line.len(), '\n')]) {
+        let current = (first.contains(&index), last.contains(&index));
+        match style {
+            Style::Brackets => {
+                // close before opening, so that equal ranges are nested
+                let brackets = [
+                    (previous.1 && !current.1, '}'),
+                    (previous.0 && !current.0, ']'),
+                    (!previous.0 && current.0, '['),
+                    (!previous.1 && current.1, '{'),
+                ];
+                output.extend(brackets.iter().filter(|(emit, _)| *emit).map(|(_, c)| c));
+            }
+            Style::Ansi if current != previous => output.push_str(match current {
+                (false, false) => "\x1b[0m",
+                (true, false) => "\x1b[32m",
+                (false, true) => "\x1b[34m",
+                (true, true) => "\x1b[36m",
+            }),
+            Style::Ansi => {}
+        }
+        if index < line.len() {
+            output.push(c);
+        }
+        previous = current;
+    }
+    output
+}
+
+/// Explain how the calibration value of every line is found, with the first
+/// and last digit highlighted, its value and the running sum. Lines without a
+/// digit are skipped.
+pub fn explain(
+    document: &str,
+    find: impl Fn(&str) -> Option<(Match, Match)>,
+    style: Style,
+) -> String {
+    let mut output = String::new();
+    let mut sum = 0u64;
+    for (line, content) in (1..).zip(document.lines()) {
+        let explanation = match find(content) {
+            Some((first, last)) => {
+                let value = 10 * first.value + last.value;
+                sum += u64::from(value);
+                let content = highlight(content, &first.range, &last.range, style);
+                format!("{content} = {value}, sum {sum}")
+            }
+            None => format!("{content} has no digit, skipped"),
+        };
+        output.push_str(&format!("{line:>4}: {explanation}\n"));
+    }
+    output
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = String;
+    type Error = CalibrationError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.into())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        calibrate(input, solve).result()
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        calibrate(input, solve2).result()
+    }
+}
+
+#[test]
+fn can_solve_sample() {
+    assert_eq!(solve("1abc2"), Ok(12));
+    assert_eq!(solve("pqr3stu8vwx"), Ok(38));
+    assert_eq!(solve("a1b2c3d4e5f"), Ok(15));
+    assert_eq!(solve("treb7uchet"), Ok(77));
+}
+
+#[test]
+fn can_solve2() {
+    assert_eq!(solve2("two1nine"), Ok(29));
+    assert_eq!(solve2("eightwothree"), Ok(83));
+    assert_eq!(solve2("abcone2threexyz"), Ok(13));
+    assert_eq!(solve2("xtwone3four"), Ok(24));
+    assert_eq!(solve2("4nineeightseven2"), Ok(42));
+    assert_eq!(solve2("zoneight234"), Ok(14));
+    assert_eq!(solve2("7pqrstsixteen"), Ok(76));
+}
+
+#[test]
+fn can_scan_overlapping() {
+    let scanner = Scanner::english();
+    assert_eq!(scanner.scan("eightwo"), Some((8, 2)));
+    assert_eq!(scanner.scan("oneight"), Some((1, 8)));
+    assert_eq!(scanner.scan("twone"), Some((2, 1)));
+    assert_eq!(scanner.scan("sevenine"), Some((7, 9)));
+    assert_eq!(scanner.scan("3"), Some((3, 3)));
+    assert_eq!(scanner.scan("abc"), None);
+    assert_eq!(scanner.scan(""), None);
+
+    // ties are broken by the longest pattern, then the smallest value
+    let scanner = Scanner::new([("ab", 5), ("a", 3), ("b", 4), ("xb", 2), ("c", 7), ("c", 6)]);
+    assert_eq!(scanner.scan("ab"), Some((5, 5)));
+    assert_eq!(scanner.scan("xab"), Some((5, 5)));
+    assert_eq!(scanner.scan("axb"), Some((3, 2)));
+    assert_eq!(scanner.scan("c"), Some((6, 6)));
+}
+
+#[test]
+fn can_use_vocabularies() {
+    let german = Vocabulary::german().scanner();
+    assert_eq!(solve_with(&german, "xzweinsiebenx"), Ok(27));
+    assert_eq!(solve_with(&german, "fünfundachtzig"), Ok(58));
+
+    let french = Vocabulary::french().scanner();
+    assert_eq!(solve_with(&french, "ahuitreize3"), Ok(83));
+    assert_eq!(solve_with(&french, "quatreneuf"), Ok(49));
+
+    let zero = Vocabulary::english_zero().scanner();
+    assert_eq!(solve_with(&zero, "zeroneight"), Ok(8));
+    assert_eq!(solve_with(&zero, "xzero"), Ok(0));
+    assert_eq!(solve2("0nine"), Ok(9));
+
+    let roman = Vocabulary::roman().scanner();
+    assert_eq!(solve_with(&roman, "xVIIIyIV"), Ok(84));
+    assert_eq!(solve_with(&roman, "IXII"), Ok(92));
+    assert_eq!(solve_with(&roman, "MCMXCIV"), Ok(44));
+}
+
+#[test]
+fn can_parse_vocabulary() {
+    let vocabulary: Vocabulary = "# numbers\nuno 1\n\ndos\t2\n  tres   3  \n"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        vocabulary.words().collect::<Vec<_>>(),
+        [("uno", 1), ("dos", 2), ("tres", 3)]
+    );
+    assert_eq!(solve_with(&vocabulary.scanner(), "dosxtresuno"), Ok(21));
+
+    assert_eq!(
+        "uno 1\ndos\n".parse::<Vocabulary>(),
+        Err(VocabularyError::Invalid {
+            line: 2,
+            content: "dos".into()
+        })
+    );
+    assert!(matches!(
+        "uno one".parse::<Vocabulary>(),
+        Err(VocabularyError::Invalid { line: 1, .. })
+    ));
+    assert_eq!(
+        "diez 10".parse::<Vocabulary>(),
+        Err(VocabularyError::Digit {
+            word: "diez".into(),
+            value: 10
+        })
+    );
+
+    assert_eq!(
+        Vocabulary::new([("tres", 3), ("cuatro", 4)]),
+        "tres 3\ncuatro 4".parse()
+    );
+    assert_eq!(
+        Vocabulary::english().with("ten", 10),
+        Err(VocabularyError::Digit {
+            word: "ten".into(),
+            value: 10
+        })
+    );
+    assert!(Vocabulary::new([("big", u32::MAX)]).is_err());
+}
+
+#[test]
+fn scanner_matches_reference() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    // short lines over an alphabet of digits and the letters of the words, so
+    // that overlapping words are frequent
+    let alphabet: Vec<char> = "123456789efghinorstuvwx".chars().collect();
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..10_000 {
+        let mut line: String = (0..rng.gen_range(1..20))
+            .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+            .collect();
+        line.insert(rng.gen_range(0..=line.len()), alphabet[rng.gen_range(0..9)]);
+        assert_eq!(solve2(&line), solve2_reference(&line), "{line}");
+    }
+    assert_eq!(solve2_reference("xyz"), Err(NoDigit::new("xyz")));
+}
+
+#[test]
+fn can_recognise_unicode_digits() {
+    assert_eq!(unicode_digit('7'), Some(7));
+    assert_eq!(unicode_digit('٣'), Some(3));
+    assert_eq!(unicode_digit('３'), Some(3));
+    assert_eq!(unicode_digit('९'), Some(9));
+    assert_eq!(unicode_digit('𝟘'), Some(0));
+    assert_eq!(unicode_digit('a'), None);
+    assert_eq!(unicode_digit('Ⅷ'), None);
+    assert_eq!(unicode_digit('½'), None);
+    assert_eq!(unicode_digit('\u{1946}'), Some(0));
+    assert_eq!(unicode_digit('\u{1945}'), None);
+
+    assert!(DECIMAL_ZEROS
+        .windows(2)
+        .all(|zeros| zeros[1] - zeros[0] >= 10));
+    assert_eq!(Digits::Ascii.chars().count(), 10);
+    assert_eq!(Digits::Unicode.chars().count(), 10 * DECIMAL_ZEROS.len());
+    for (c, value) in Digits::Unicode.chars() {
+        assert!(c.is_numeric(), "{c:?}");
+        assert_eq!(Digits::Unicode.value(c), Some(value));
+    }
+}
+
+#[test]
+fn can_solve_mixed_scripts() {
+    // arabic-indic, fullwidth, devanagari and mathematical digits
+    let lines = ["a٣b7c", "３pqr８", "x९yz", "𝟏one𝟐", "１٢३"];
+    let values: Vec<u32> = lines
+        .iter()
+        .map(|line| solve_digits(line, Digits::Unicode).unwrap())
+        .collect();
+    assert_eq!(values, [37, 38, 99, 12, 13]);
+    assert_eq!(solve("a٣b7c"), Ok(77));
+
+    let scanner = Vocabulary::english().scanner_with(Digits::Unicode);
+    assert_eq!(solve_with(&scanner, "٣twoeightwo"), Ok(32));
+    assert_eq!(solve_with(&scanner, "nine４"), Ok(94));
+    assert_eq!(solve_with(&scanner, "x𝟘sevenine５y"), Ok(5));
+    assert_eq!(solve_with(&scanner, "zwei꩓"), Ok(33));
+    assert_eq!(solve_with(Scanner::english(), "٣twoeightwo"), Ok(22));
+}
+
+#[test]
+fn can_report_lines_without_digits() {
+    assert_eq!(
+        solve("abc"),
+        Err(NoDigit {
+            line: 1,
+            content: "abc".into()
+        })
+    );
+    assert!(solve2("abc").is_err());
+
+    let document = "1abc2\nnone\ntwo1nine\n\nthree\n";
+    let calibration = calibrate(document, solve);
+    assert_eq!(calibration.sum, 12 + 11);
+    let lines: Vec<(usize, &str)> = calibration
+        .errors
+        .iter()
+        .map(|error| (error.line, error.content.as_str()))
+        .collect();
+    assert_eq!(lines, [(2, "none"), (4, ""), (5, "three")]);
+    let error = calibration.result().unwrap_err();
+    assert_eq!(error.to_string(), "lines 2, 4, 5 have no digit");
+
+    // "none" contains "one"
+    let error = calibrate(document, solve2).result().unwrap_err();
+    assert_eq!(error.to_string(), "line 4 has no digit: \"\"");
+    assert_eq!(calibrate(document, solve2).sum, 12 + 11 + 29 + 33);
+    assert_eq!(Day01::part1(&"1\n2\n".into()), Ok(33));
+}
+
+#[test]
+fn can_find_spans() {
+    let scanner = Scanner::english();
+    let spans = |line| {
+        let (first, last) = scanner.find(line).unwrap();
+        (first.range, first.value, last.range, last.value)
+    };
+    assert_eq!(spans("eightwo"), (0..5, 8, 4..7, 2));
+    assert_eq!(spans("x7y"), (1..2, 7, 1..2, 7));
+    assert_eq!(spans("ab1cdtwoeightwoab"), (2..3, 1, 12..15, 2));
+
+    let scanner = Vocabulary::english().scanner_with(Digits::Unicode);
+    let (first, last) = scanner.find("x٣one").unwrap();
+    assert_eq!((first.range, last.range), (1..3, 3..6));
+    let (first, last) = find_digits("x٣y", Digits::Unicode).unwrap();
+    assert_eq!((first.range, last.range), (1..3, 1..3));
+}
+
+#[test]
+fn can_highlight() {
+    let brackets = |line, first, last| highlight(line, &first, &last, Style::Brackets);
+    assert_eq!(brackets("two1nine", 0..3, 4..8), "[two]1{nine}");
+    assert_eq!(brackets("eightwo", 0..5, 4..7), "[eigh{t]wo}");
+    assert_eq!(brackets("x7y", 1..2, 1..2), "x[{7}]y");
+    assert_eq!(brackets("٣x٣", 0..2, 3..5), "[٣]x{٣}");
+    assert_eq!(
+        highlight("eightwo", &(0..5), &(4..7), Style::Ansi),
+        "\x1b[32meigh\x1b[36mt\x1b[34mwo\x1b[0m"
+    );
+}
+
+#[test]
+fn can_explain() {
+    let document = "two1nine\nabc\neightwothree\n";
+    let explanation = explain(
+        document,
+        |line| Scanner::english().find(line),
+        Style::Brackets,
+    );
+    let expected = [
+        "   1: [two]1{nine} = 29, sum 29\n",
+        "   2: abc has no digit, skipped\n",
+        "   3: [eight]wo{three} = 83, sum 112\n",
+    ];
+    assert_eq!(explanation, expected.concat());
+    let explanation = explain(
+        "a1b2\n",
+        |line| find_digits(line, Digits::Ascii),
+        Style::Brackets,
+    );
+    assert_eq!(explanation, "   1: a[1]b{2} = 12, sum 12\n");
+}
+
+#[test]
+fn can_calibrate_bytes() {
+    let document = "1abc2\r\nnone\ntwo1nine\n\nthree";
+    let part1 = Scanner::digits(Digits::Ascii);
+    let [first, second] = calibrate_bytes(document.as_bytes(), [&part1, Scanner::english()]);
+    assert_eq!(first, calibrate(document, solve));
+    assert_eq!(second, calibrate(document, solve2));
+    assert_eq!(calibrate_bytes(b"", [&part1]), [Calibration::default()]);
+}
+
+#[test]
+fn can_stream() {
+    let document = include_str!("../sample2.txt").repeat(10) + "xyz\n" + &"nine".repeat(30);
+    let expected = calibrate_bytes(document.as_bytes(), [Scanner::english()]);
+    assert_eq!(expected[0].sum, 2810 + 99);
+    assert_eq!(expected[0].errors.len(), 1);
+
+    // small buffers split lines, and have to grow for the long last line
+    for capacity in [1, 2, 3, 7, 16, 1024] {
+        let calibrations = stream_with(document.as_bytes(), [Scanner::english()], capacity);
+        assert_eq!(calibrations.unwrap(), expected, "capacity {capacity}");
+    }
+    let calibrations = stream(document.as_bytes(), [Scanner::english()]).unwrap();
+    assert_eq!(calibrations, expected);
+}
+
+#[test]
+fn can_run() {
+    let input = Day01::parse(include_str!("../sample1.txt")).unwrap();
+    assert_eq!(Day01::part1(&input).unwrap(), 142);
+    let input = Day01::parse(include_str!("../sample2.txt")).unwrap();
+    assert_eq!(Day01::part2(&input).unwrap(), 281);
+}
@@ -1,91 +1,131 @@
-use std::{io::*, iter::once};
+use aoc_common::input::read_stdin;
+use clap::{Parser, ValueEnum};
+use day01::{calibrate_bytes, explain, stream, Digits, Scanner, Style, Vocabulary};
+use std::{io::stdin, path::PathBuf, process::exit};
 
-/// Find the first and last digit in a string, and turn them into a number.
-fn solve(input: &str) -> u32 {
-    let mut numbers = input.chars().flat_map(|c| c.to_digit(10));
-    let first = numbers.next().unwrap();
-    let last = numbers.last().unwrap_or(first);
-    let number = 10 * first + last;
-    number
+/// Built-in vocabularies of spelled-out digits.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Builtin {
+    English,
+    EnglishZero,
+    German,
+    French,
+    Roman,
 }
 
-fn prefixes(s: &str) -> impl Iterator<Item = &str> + DoubleEndedIterator {
-    s.char_indices()
-        .map(move |(pos, _)| &s[..pos])
-        .chain(once(s))
+impl From<Builtin> for Vocabulary {
+    fn from(builtin: Builtin) -> Self {
+        match builtin {
+            Builtin::English => Vocabulary::english(),
+            Builtin::EnglishZero => Vocabulary::english_zero(),
+            Builtin::German => Vocabulary::german(),
+            Builtin::French => Vocabulary::french(),
+            Builtin::Roman => Vocabulary::roman(),
+        }
+    }
 }
 
-fn suffixes(s: &str) -> impl Iterator<Item = &str> + DoubleEndedIterator {
-    s.char_indices()
-        .map(move |(pos, _)| &s[pos..])
-        .chain(once(""))
-        .rev()
+/// How to highlight the digits when explaining.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Highlight {
+    Brackets,
+    Ansi,
 }
 
-/// Find the first and last digit in a string, but the digit may also be written
-/// out as text.
-fn solve2(input: &str) -> u32 {
-    let digits: [&[&'static str]; 10] = [
-        &["0"],
-        &["one", "1"],
-        &["two", "2"],
-        &["three", "3"],
-        &["four", "4"],
-        &["five", "5"],
-        &["six", "6"],
-        &["seven", "7"],
-        &["eight", "8"],
-        &["nine", "9"],
-    ];
+impl From<Highlight> for Style {
+    fn from(highlight: Highlight) -> Self {
+        match highlight {
+            Highlight::Brackets => Style::Brackets,
+            Highlight::Ansi => Style::Ansi,
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Solve day 1, reading the input from standard input")]
+struct Options {
+    /// Words for the spelled-out digits of part two.
+    #[arg(long, value_enum, default_value = "english")]
+    vocabulary: Builtin,
 
-    let first = suffixes(input)
-        .rev()
-        .flat_map(|prefix| {
-            digits
-                .iter()
-                .enumerate()
-                .find(|(_, patterns)| patterns.iter().any(|pattern| prefix.starts_with(pattern)))
-                .map(|(index, _)| index)
-        })
-        .next()
-        .unwrap();
+    /// Load the words for part two from a file instead, with a word and its
+    /// value on every line.
+    #[arg(long, conflicts_with = "vocabulary")]
+    vocabulary_file: Option<PathBuf>,
 
-    let last = prefixes(input)
-        .rev()
-        .flat_map(|prefix| {
-            digits
-                .iter()
-                .enumerate()
-                .find(|(_, patterns)| patterns.iter().any(|pattern| prefix.ends_with(pattern)))
-                .map(|(index, _)| index)
-        })
-        .next()
-        .unwrap();
+    /// Recognise decimal digits of any script, not only ASCII digits.
+    #[arg(long)]
+    unicode: bool,
 
-    (10 * first + last) as u32
+    /// Skip lines without a digit, instead of failing.
+    #[arg(long)]
+    lenient: bool,
+
+    /// Print every line with the first and last digit highlighted, its value
+    /// and the running sum.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "brackets")]
+    explain: Option<Highlight>,
 }
 
 fn main() {
-    let input = BufReader::new(stdin());
-    let solution: u32 = input.lines().map(|line| solve2(&line.unwrap())).sum();
-    println!("{solution}");
-}
+    let options = Options::parse();
+    let vocabulary: Vocabulary = match &options.vocabulary_file {
+        Some(path) => match std::fs::read_to_string(path).map(|file| file.parse()) {
+            Ok(Ok(vocabulary)) => vocabulary,
+            Ok(Err(error)) => {
+                eprintln!("error: {}: {error}", path.display());
+                exit(1);
+            }
+            Err(error) => {
+                eprintln!("error: reading {}: {error}", path.display());
+                exit(1);
+            }
+        },
+        None => options.vocabulary.into(),
+    };
 
-#[test]
-fn can_solve_sample() {
-    assert_eq!(solve("1abc2"), 12);
-    assert_eq!(solve("pqr3stu8vwx"), 38);
-    assert_eq!(solve("a1b2c3d4e5f"), 15);
-    assert_eq!(solve("treb7uchet"), 77);
-}
+    let digits = match options.unicode {
+        true => Digits::Unicode,
+        false => Digits::Ascii,
+    };
+
+    let part1 = Scanner::digits(digits);
+    let part2 = vocabulary.scanner_with(digits);
+    let result = match options.explain {
+        Some(highlight) => read_stdin().map(|input| {
+            let style = highlight.into();
+            println!("part1");
+            print!("{}", explain(&input, |line| part1.find(line), style));
+            println!("part2");
+            print!("{}", explain(&input, |line| part2.find(line), style));
+            calibrate_bytes(input.as_bytes(), [&part1, &part2])
+        }),
+        None => stream(stdin().lock(), [&part1, &part2]),
+    };
+    let parts = match result {
+        Ok([part1, part2]) => [("part1", part1), ("part2", part2)],
+        Err(error) => {
+            eprintln!("error: reading input: {error}");
+            exit(1);
+        }
+    };
+
+    let mut failed = false;
+    for (part, calibration) in parts {
+        for error in &calibration.errors {
+            match options.lenient {
+                true => eprintln!("warning: {part} skipped {error}"),
+                false => eprintln!("error: {part} {error}"),
+            }
+        }
+        if options.lenient || calibration.errors.is_empty() {
+            println!("{part} {}", calibration.sum);
+        } else {
+            failed = true;
+        }
+    }
 
-#[test]
-fn can_solve2() {
-    assert_eq!(solve2("two1nine"), 29);
-    assert_eq!(solve2("eightwothree"), 83);
-    assert_eq!(solve2("abcone2threexyz"), 13);
-    assert_eq!(solve2("xtwone3four"), 24);
-    assert_eq!(solve2("4nineeightseven2"), 42);
-    assert_eq!(solve2("zoneight234"), 14);
-    assert_eq!(solve2("7pqrstsixteen"), 76);
+    if failed {
+        exit(1);
+    }
 }
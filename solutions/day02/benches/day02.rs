@@ -0,0 +1,52 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day02::{Day02, Game};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::{hint::black_box, str::FromStr};
+
+/// Generate games with random reaches.
+fn generate(games: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(2);
+    let mut output = String::new();
+    for id in 1..=games {
+        let reaches: Vec<String> = (0..rng.gen_range(1..8))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(&mut rng);
+                let count = rng.gen_range(1..=3);
+                colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.gen_range(1..20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        output.push_str(&format!("Game {id}: {}\n", reaches.join("; ")));
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    let inputs = [
+        ("sample", include_str!("../sample.txt").to_string()),
+        ("generated", generate(10_000)),
+    ];
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day02/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day02::parse(input).unwrap();
+        group.bench_function("parse", |b| b.iter(|| Day02::parse(black_box(input))));
+        group.bench_function("part1", |b| b.iter(|| Day02::part1(black_box(&parsed))));
+        group.bench_function("part2", |b| b.iter(|| Day02::part2(black_box(&parsed))));
+        group.finish();
+    }
+
+    let line = include_str!("../sample.txt").lines().nth(2).unwrap();
+    c.bench_function("day02/Game::from_str", |b| {
+        b.iter(|| Game::from_str(black_box(line)))
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
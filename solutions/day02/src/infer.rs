@@ -0,0 +1,291 @@
+//! Estimate the contents of the bag from the observed reaches.
+//!
+//! Every reach is modelled as drawing a handful of cubes from the bag without
+//! replacement, and putting them back afterwards. For a bag with `N[c]` cubes
+//! of every color `c` and `N` cubes in total, a reach of `n` cubes of which
+//! `k[c]` have color `c` has the multivariate hypergeometric likelihood
+//!
+//! ```text
+//! prod(binomial(N[c], k[c])) / binomial(N, n)
+//! ```
+//!
+//! With a uniform prior on `0..=limit` cubes of every color, the marginal
+//! posterior of every color is computed exactly by convolving the per-color
+//! likelihoods in log space, since the colors are only coupled through `N`.
+
+use crate::{Color, Reach};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum InferError {
+    #[error("{minimum} {color} cubes were seen at once, more than the limit of {limit}")]
+    Limit {
+        color: Color,
+        minimum: usize,
+        limit: usize,
+    },
+    #[error("confidence {0} is not between 0 and 1")]
+    Confidence(String),
+}
+
+/// Estimated number of cubes of one color in the bag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub color: Color,
+    /// Fewest cubes that make every reach possible.
+    pub minimum: usize,
+    /// Most likely number of cubes.
+    pub mode: usize,
+    /// Expected number of cubes.
+    pub mean: f64,
+    /// Equal-tailed credible interval for the number of cubes.
+    pub interval: (usize, usize),
+    /// Whether the posterior has not died down by the limit, because the reaches
+    /// do not rule out larger bags. The estimate then depends on the limit
+    /// rather than on the reaches, and is not identifiable.
+    pub at_limit: bool,
+}
+
+/// Logarithm of the sum of exponentials, ignoring impossible (`-inf`) terms.
+fn log_sum_exp(values: impl IntoIterator<Item = f64> + Clone) -> f64 {
+    let max = values.clone().into_iter().fold(f64::NEG_INFINITY, f64::max);
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+    max + values
+        .into_iter()
+        .map(|value| (value - max).exp())
+        .sum::<f64>()
+        .ln()
+}
+
+/// Convolution of two distributions given as log weights.
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    (0..a.len() + b.len() - 1)
+        .map(|total| {
+            let first = total.saturating_sub(b.len() - 1);
+            let last = total.min(a.len() - 1);
+            log_sum_exp((first..=last).map(|x| a[x] + b[total - x]))
+        })
+        .collect()
+}
+
+/// Table of `ln(n!)` for computing binomial coefficients.
+struct Factorials(Vec<f64>);
+
+impl Factorials {
+    fn new(max: usize) -> Self {
+        let mut table = Vec::with_capacity(max + 1);
+        table.push(0.0);
+        for n in 1..=max {
+            table.push(table[n - 1] + (n as f64).ln());
+        }
+        Factorials(table)
+    }
+
+    /// Logarithm of the binomial coefficient, `-inf` if `k > n`.
+    fn binomial(&self, n: usize, k: usize) -> f64 {
+        match k <= n {
+            true => self.0[n] - self.0[k] - self.0[n - k],
+            false => f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// Estimate the contents of the bag from the reaches, assuming at most
+/// `limit` cubes of every color and reporting intervals with the given
+/// `confidence`.
+pub fn infer<'a>(
+    reaches: impl IntoIterator<Item = &'a Reach>,
+    limit: usize,
+    confidence: f64,
+) -> Result<Vec<Estimate>, InferError> {
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(InferError::Confidence(confidence.to_string()));
+    }
+
+    let reaches: Vec<&Reach> = reaches.into_iter().collect();
+    let mut minimum: BTreeMap<Color, usize> = BTreeMap::new();
+    for (color, count) in reaches.iter().flat_map(|reach| reach.colors()) {
+        let entry = minimum.entry(color).or_default();
+        *entry = count.max(*entry);
+    }
+    if let Some((color, minimum)) = minimum.iter().find(|(_, minimum)| **minimum > limit) {
+        return Err(InferError::Limit {
+            color: *color,
+            minimum: *minimum,
+            limit,
+        });
+    }
+
+    let colors: Vec<Color> = minimum.keys().copied().collect();
+    let factorials = Factorials::new(colors.len() * limit);
+
+    // log likelihood of every count of a color, up to the shared total
+    let likelihoods: Vec<Vec<f64>> = colors
+        .iter()
+        .map(|color| {
+            (0..=limit)
+                .map(|count| {
+                    reaches
+                        .iter()
+                        .map(|reach| factorials.binomial(count, reach.get(*color)))
+                        .sum()
+                })
+                .collect()
+        })
+        .collect();
+    // log likelihood of the shared total, impossible if it is smaller than a reach
+    let totals: Vec<f64> = (0..=colors.len() * limit)
+        .map(|total| {
+            reaches
+                .iter()
+                .map(|reach| {
+                    let size = reach.colors().map(|(_, count)| count).sum();
+                    match size <= total {
+                        true => -factorials.binomial(total, size),
+                        false => f64::NEG_INFINITY,
+                    }
+                })
+                .sum()
+        })
+        .collect();
+
+    let mut estimates = vec![];
+    for (index, color) in colors.iter().enumerate() {
+        // distribution of the number of cubes of all other colors
+        let others = likelihoods
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .fold(vec![0.0], |others, (_, likelihood)| {
+                convolve(&others, likelihood)
+            });
+
+        let posterior: Vec<f64> = likelihoods[index]
+            .iter()
+            .enumerate()
+            .map(|(count, likelihood)| {
+                likelihood
+                    + log_sum_exp(
+                        others
+                            .iter()
+                            .enumerate()
+                            .map(|(rest, others)| others + totals[count + rest]),
+                    )
+            })
+            .collect();
+        let norm = log_sum_exp(posterior.iter().copied());
+        let posterior: Vec<f64> = posterior.iter().map(|value| (value - norm).exp()).collect();
+
+        let mode = (0..posterior.len())
+            .max_by(|a, b| posterior[*a].total_cmp(&posterior[*b]))
+            .unwrap_or(0);
+        let mean = posterior
+            .iter()
+            .enumerate()
+            .map(|(count, p)| count as f64 * p)
+            .sum();
+        let tail = (1.0 - confidence) / 2.0;
+        let quantile = |target: f64| {
+            let mut sum = 0.0;
+            posterior
+                .iter()
+                .position(|p| {
+                    sum += p;
+                    sum > target
+                })
+                .unwrap_or(limit)
+        };
+
+        estimates.push(Estimate {
+            color: *color,
+            minimum: minimum[color],
+            mode,
+            mean,
+            interval: (quantile(tail), quantile(1.0 - tail)),
+            at_limit: posterior[limit] > tail * posterior[mode],
+        });
+    }
+
+    Ok(estimates)
+}
+
+#[test]
+fn can_convolve() {
+    let a = [0.5f64.ln(), 0.5f64.ln()];
+    let b = [0.25f64.ln(), 0.75f64.ln()];
+    let c: Vec<f64> = convolve(&a, &b).into_iter().map(f64::exp).collect();
+    for (c, expected) in c.iter().zip([0.125, 0.5, 0.375]) {
+        assert!((c - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn can_infer_minimum() {
+    let games: Vec<crate::Game> =
+        aoc_common::split::parse_lines(include_str!("../sample.txt")).unwrap();
+    let estimates = infer(games.iter().flat_map(|game| game.reaches()), 30, 0.9).unwrap();
+    let colors: Vec<(&str, usize)> = estimates
+        .iter()
+        .map(|estimate| (estimate.color.name(), estimate.minimum))
+        .collect();
+    assert_eq!(colors, [("blue", 15), ("green", 13), ("red", 20)]);
+    for estimate in &estimates {
+        assert!(estimate.minimum <= estimate.interval.0);
+        assert!(estimate.interval.0 <= estimate.mode && estimate.mode <= estimate.interval.1);
+        assert!(estimate.interval.1 <= 30);
+        assert!(estimate.minimum as f64 <= estimate.mean);
+    }
+
+    assert_eq!(
+        infer(games.iter().flat_map(|game| game.reaches()), 19, 0.9),
+        Err(InferError::Limit {
+            color: Color::RED,
+            minimum: 20,
+            limit: 19
+        })
+    );
+}
+
+#[test]
+fn can_infer_proportions() {
+    // drawing 4 cubes at a time, an eighth of them red
+    let reaches: Vec<Reach> = (0..200)
+        .map(|i| match i % 2 {
+            0 => "1 red, 3 blue",
+            _ => "4 blue",
+        })
+        .map(|reach| reach.parse().unwrap())
+        .collect();
+    let estimates = infer(&reaches, 40, 0.95).unwrap();
+    let [blue, red] = &estimates[..] else {
+        panic!("expected two colors");
+    };
+    assert_eq!(blue.color, Color::BLUE);
+    assert_eq!(red.color, Color::RED);
+
+    // the ratio of red to blue cubes is about 1 to 7
+    let ratio = red.mean / blue.mean;
+    assert!((0.1..0.2).contains(&ratio), "ratio {ratio}");
+    assert_eq!(red.minimum, 1);
+    assert!(!red.at_limit && !blue.at_limit);
+    assert!(red.interval.0 >= 1);
+    assert!(red.interval.1 < blue.interval.1);
+}
+
+#[test]
+fn can_detect_limit() {
+    // a couple of reaches say little about how large the bag is
+    let reaches: Vec<Reach> = ["1 red, 2 blue", "2 red, 1 blue"]
+        .into_iter()
+        .map(|reach| reach.parse().unwrap())
+        .collect();
+    for limit in [20, 100] {
+        let estimates = infer(&reaches, limit, 0.95).unwrap();
+        assert!(estimates.iter().all(|estimate| estimate.at_limit));
+        // the estimate grows with the limit instead of settling
+        assert!(estimates[0].mean > limit as f64 / 2.0);
+    }
+}
@@ -0,0 +1,707 @@
+use aoc_common::Solution;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    io,
+    num::ParseIntError,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Mutex,
+};
+use thiserror::Error;
+
+pub mod infer;
+pub mod query;
+pub mod span;
+
+use span::Spanned;
+
+/// Names of all colors seen so far, leaked so that colors are cheap to copy.
+static COLORS: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Color of a cube, interned by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Color(&'static str);
+
+impl Color {
+    pub const RED: Color = Color("red");
+    pub const GREEN: Color = Color("green");
+    pub const BLUE: Color = Color("blue");
+
+    /// Intern a color by name.
+    ///
+    /// Every distinct name is leaked once and kept for the rest of the
+    /// process, which is intended: a run only sees the handful of colors in
+    /// its input and bag. Interning an unbounded number of distinct names,
+    /// such as from untrusted input in a long-running process, grows memory
+    /// without limit.
+    pub fn new(name: &str) -> Self {
+        if let Some(color) = [Color::RED, Color::GREEN, Color::BLUE]
+            .into_iter()
+            .find(|color| color.0 == name)
+        {
+            return color;
+        }
+
+        let mut colors = COLORS.lock().unwrap_or_else(|error| error.into_inner());
+        match colors.get(name) {
+            Some(name) => Color(name),
+            None => {
+                let name: &'static str = Box::leak(name.into());
+                colors.insert(name);
+                Color(name)
+            }
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid color {0:?}")]
+pub struct InvalidColor(pub String);
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.is_empty() || !input.chars().all(char::is_lowercase) {
+            return Err(InvalidColor(input.into()));
+        }
+        Ok(Color::new(input))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    id: u64,
+    reaches: Vec<Reach>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("missing game delimiter")]
+    MissingGameDelimiter,
+    #[error("missing game number")]
+    MissingGameNumber,
+    #[error("invalid game number: {0}")]
+    ParseGameNumber(#[from] ParseIntError),
+    #[error(transparent)]
+    ParseReach(#[from] ParseReachError),
+}
+
+impl FromStr for Game {
+    type Err = Spanned<ParseError>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use ParseError::*;
+
+        let Some((game, reaches)) = input.split_once(": ") else {
+            return Err(Spanned::new(MissingGameDelimiter, input, input));
+        };
+
+        let Some(number) = game.split(' ').nth(1) else {
+            return Err(Spanned::new(MissingGameNumber, input, game));
+        };
+        let id: u64 = number
+            .parse()
+            .map_err(|error| Spanned::new(ParseGameNumber(error), input, number))?;
+
+        let reaches = reaches
+            .trim()
+            .split("; ")
+            .map(|reach| {
+                reach.parse().map_err(|error: Spanned<ParseReachError>| {
+                    error.map(ParseReach).within(input, reach)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Game { id, reaches })
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (index, reach) in self.reaches.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{reach}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Game {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn reaches(&self) -> &[Reach] {
+        &self.reaches
+    }
+
+    /// Smallest set of cubes that makes every reach of this game possible.
+    pub fn minimum(&self) -> Config {
+        let mut colors = BTreeMap::new();
+        for reach in &self.reaches {
+            for (color, count) in reach.colors() {
+                let entry = colors.entry(color).or_default();
+                *entry = count.max(*entry);
+            }
+        }
+        Config::new(colors)
+    }
+
+    /// Power of the minimum set of cubes, if it does not overflow.
+    pub fn power(&self) -> Option<usize> {
+        self.minimum().power()
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseReachError {
+    #[error("empty reach, expected at least one color")]
+    Empty,
+    #[error("invalid reach, expected a number and a color")]
+    Invalid,
+    #[error("invalid number: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+    #[error(transparent)]
+    InvalidColor(#[from] InvalidColor),
+}
+
+/// Cubes revealed at once, in the order they were listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reach {
+    colors: Vec<(Color, usize)>,
+}
+
+impl FromStr for Reach {
+    type Err = Spanned<ParseReachError>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use ParseReachError::*;
+        if input.is_empty() {
+            return Err(Spanned::new(Empty, input, input));
+        }
+
+        let mut colors = vec![];
+        for entry in input.split(", ") {
+            let Some((number, color)) = entry.split_once(' ') else {
+                return Err(Spanned::new(Invalid, input, entry));
+            };
+
+            let number: usize = number
+                .parse()
+                .map_err(|error| Spanned::new(InvalidNumber(error), input, number))?;
+            let color: Color = color
+                .parse()
+                .map_err(|error| Spanned::new(InvalidColor(error), input, color))?;
+            colors.push((color, number));
+        }
+        Ok(Reach { colors })
+    }
+}
+
+impl Display for Reach {
+    /// Write the cubes of every color in the order they were listed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (color, count)) in self.colors.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{count} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Reach {
+    /// Number of cubes of the color in this reach.
+    pub fn get(&self, color: Color) -> usize {
+        self.colors
+            .iter()
+            .filter(|(other, _)| *other == color)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Number of cubes of every color in this reach, in the order the colors
+    /// are first listed.
+    pub fn colors(&self) -> impl Iterator<Item = (Color, usize)> + '_ {
+        self.colors
+            .iter()
+            .enumerate()
+            .filter(|(index, (color, _))| {
+                self.colors[..*index]
+                    .iter()
+                    .all(|(other, _)| other != color)
+            })
+            .map(|(_, (color, _))| (*color, self.get(*color)))
+    }
+}
+
+/// How to treat colors in a reach that the bag does not know about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unknown {
+    /// Any reach with an unknown color is impossible.
+    #[default]
+    Reject,
+    /// The bag has no cubes of unknown colors, so only reaches of zero cubes
+    /// of them are possible.
+    Zero,
+}
+
+/// Error reading the contents of a bag.
+#[derive(Error, Debug)]
+pub enum BagError {
+    #[error("invalid bag entry {0:?}, expected color=count")]
+    Entry(String),
+    #[error("invalid count in bag entry {entry:?}: {source}")]
+    Count {
+        entry: String,
+        source: ParseIntError,
+    },
+    #[error(transparent)]
+    Color(#[from] InvalidColor),
+    #[error("color {0} appears more than once")]
+    Duplicate(Color),
+    #[error("reading {path}: {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("parsing {path}: {source}")]
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("parsing {path}: {source}")]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    colors: BTreeMap<Color, usize>,
+    unknown: Unknown,
+}
+
+impl Config {
+    pub fn new(colors: impl IntoIterator<Item = (Color, usize)>) -> Self {
+        Config {
+            colors: colors.into_iter().collect(),
+            unknown: Unknown::default(),
+        }
+    }
+
+    /// The bag of the puzzle, with 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        Config::new([(Color::RED, 12), (Color::GREEN, 13), (Color::BLUE, 14)])
+    }
+
+    /// Build a bag from color names and counts, rejecting invalid and
+    /// duplicate colors with an error made from the name they were found at.
+    fn from_names<'a, E>(
+        colors: impl IntoIterator<Item = (&'a str, usize)>,
+        error: impl Fn(BagError, &'a str) -> E,
+    ) -> Result<Self, E> {
+        let mut bag = BTreeMap::new();
+        for (name, count) in colors {
+            let color: Color = name
+                .parse()
+                .map_err(|invalid| error(BagError::Color(invalid), name))?;
+            if bag.insert(color, count).is_some() {
+                return Err(error(BagError::Duplicate(color), name));
+            }
+        }
+        Ok(Config::new(bag))
+    }
+
+    /// Load a bag from a TOML or JSON file (depending on the extension)
+    /// mapping color names to counts.
+    pub fn load(path: &Path) -> Result<Self, BagError> {
+        let data = std::fs::read_to_string(path).map_err(|source| BagError::Read {
+            path: path.into(),
+            source,
+        })?;
+        let colors: BTreeMap<String, usize> = match path.extension() {
+            Some(extension) if extension == "json" => {
+                serde_json::from_str(&data).map_err(|source| BagError::Json {
+                    path: path.into(),
+                    source,
+                })?
+            }
+            _ => toml::from_str(&data).map_err(|source| BagError::Toml {
+                path: path.into(),
+                source,
+            })?,
+        };
+        let colors = colors.iter().map(|(name, count)| (name.as_str(), *count));
+        Config::from_names(colors, |error, _| error)
+    }
+
+    /// Set how colors missing from this bag are treated.
+    pub fn with_unknown(mut self, unknown: Unknown) -> Self {
+        self.unknown = unknown;
+        self
+    }
+
+    /// Number of cubes of the color in the bag.
+    pub fn get(&self, color: Color) -> usize {
+        self.colors.get(&color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (Color, usize)> + '_ {
+        self.colors.iter().map(|(color, count)| (*color, *count))
+    }
+
+    /// Product of the number of red, green and blue cubes, if it does not
+    /// overflow.
+    pub fn power(&self) -> Option<usize> {
+        [Color::RED, Color::GREEN, Color::BLUE]
+            .into_iter()
+            .try_fold(1usize, |power, color| power.checked_mul(self.get(color)))
+    }
+
+    pub fn possible_reach(&self, reach: &Reach) -> bool {
+        for (color, count) in reach.colors() {
+            let possible = match (self.colors.get(&color), self.unknown) {
+                (Some(possible), _) => *possible,
+                (None, Unknown::Reject) => return false,
+                (None, Unknown::Zero) => 0,
+            };
+
+            if count > possible {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    pub fn possible_game(&self, game: &Game) -> bool {
+        game.reaches.iter().all(|reach| self.possible_reach(reach))
+    }
+}
+
+impl FromStr for Config {
+    type Err = Spanned<BagError>;
+
+    /// Parse a bag written as `red=12,green=13,blue=14`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut colors = vec![];
+        for entry in input.split(',').map(str::trim) {
+            let Some((name, count)) = entry.split_once('=') else {
+                return Err(Spanned::new(BagError::Entry(entry.into()), input, entry));
+            };
+            let count = count.trim();
+            let count = count.parse().map_err(|source| {
+                let error = BagError::Count {
+                    entry: entry.into(),
+                    source,
+                };
+                Spanned::new(error, input, count)
+            })?;
+            colors.push((name.trim(), count));
+        }
+        Config::from_names(colors, |error, name| Spanned::new(error, input, name))
+    }
+}
+
+#[test]
+fn can_parse_bag() {
+    let bag: Config = "red=12,green=13,blue=14".parse().unwrap();
+    assert_eq!(bag, Config::puzzle());
+    let bag: Config = "yellow = 2, red = 1".parse().unwrap();
+    assert_eq!(bag.get(Color::new("yellow")), 2);
+    assert_eq!(bag.get(Color::RED), 1);
+    assert_eq!(bag.get(Color::BLUE), 0);
+
+    let error = |input: &str| input.parse::<Config>().unwrap_err();
+    assert!(matches!(error("red").error, BagError::Entry(_)));
+    let count = error("red=1, green=x");
+    assert!(matches!(count.error, BagError::Count { .. }));
+    assert_eq!((count.span.column, count.span.text.as_str()), (14, "x"));
+    assert!(matches!(error("Red=1").error, BagError::Color(_)));
+    let duplicate = error("red=1,red=2");
+    assert!(matches!(duplicate.error, BagError::Duplicate(Color::RED)));
+    assert_eq!(duplicate.span.offset, 6);
+}
+
+#[test]
+fn can_load_bag() {
+    let temp = tempfile::tempdir().unwrap();
+    let directory = temp.path();
+
+    let path = directory.join("bag.toml");
+    std::fs::write(&path, "red = 12\ngreen = 13\nblue = 14\n").unwrap();
+    assert_eq!(Config::load(&path).unwrap(), Config::puzzle());
+
+    let path = directory.join("bag.json");
+    std::fs::write(&path, r#"{"red": 12, "green": 13, "blue": 14}"#).unwrap();
+    assert_eq!(Config::load(&path).unwrap(), Config::puzzle());
+
+    std::fs::write(&path, "red = 12").unwrap();
+    assert!(matches!(Config::load(&path), Err(BagError::Json { .. })));
+    assert!(matches!(
+        Config::load(&directory.join("missing.toml")),
+        Err(BagError::Read { .. })
+    ));
+}
+
+#[test]
+fn can_parse() {
+    let game: Game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+        .parse()
+        .unwrap();
+    assert_eq!(game.id, 1);
+    assert_eq!(game.reaches.len(), 3);
+    assert_eq!(game.reaches[0].colors, [(Color::BLUE, 3), (Color::RED, 4)]);
+    assert_eq!(
+        game.reaches[1].colors,
+        [(Color::RED, 1), (Color::GREEN, 2), (Color::BLUE, 6)]
+    );
+    assert_eq!(game.reaches[2].colors, [(Color::GREEN, 2)]);
+}
+
+#[test]
+fn can_locate_errors() {
+    let error = "Game 1: 3 blue, 4 purple!".parse::<Game>().unwrap_err();
+    assert!(matches!(
+        error.error,
+        ParseError::ParseReach(ParseReachError::InvalidColor(_))
+    ));
+    assert_eq!((error.span.offset, error.span.column), (18, 19));
+    assert_eq!(error.span.text, "purple!");
+    assert_eq!(
+        error.to_string(),
+        "invalid color \"purple!\" at line 1, column 19"
+    );
+    assert_eq!(
+        error.snippet(),
+        "  |\n1 | Game 1: 3 blue, 4 purple!\n  |                   ^^^^^^^\n"
+    );
+
+    let error = "Game x: 3 blue".parse::<Game>().unwrap_err();
+    assert!(matches!(error.error, ParseError::ParseGameNumber(_)));
+    assert_eq!((error.span.column, error.span.text.as_str()), (6, "x"));
+    let error = "Game 1: 3 blue, red".parse::<Game>().unwrap_err();
+    assert_eq!(
+        error.error,
+        ParseError::ParseReach(ParseReachError::Invalid)
+    );
+    assert_eq!(error.span.text, "red");
+    let error = "Game 1 3 blue".parse::<Game>().unwrap_err();
+    assert_eq!(error.error, ParseError::MissingGameDelimiter);
+
+    let input = "Game 1: 1 red\nGame 2: 1 red; x green\n";
+    let error = parse_games(input).unwrap_err();
+    assert_eq!((error.span.line, error.span.column), (2, 16));
+    assert_eq!(error.span.offset, 29);
+    assert_eq!(error.span.context, "Game 2: 1 red; x green");
+}
+
+#[test]
+fn can_display() {
+    for line in include_str!("../sample.txt").lines() {
+        let game: Game = line.parse().unwrap();
+        assert_eq!(game.to_string(), line);
+    }
+
+    let game: Game = "Game 7: 1 red, 2 blue, 3 red".parse().unwrap();
+    assert_eq!(game.to_string(), "Game 7: 1 red, 2 blue, 3 red");
+    assert_eq!(game.reaches[0].get(Color::RED), 4);
+    let colors: Vec<_> = game.reaches[0].colors().collect();
+    assert_eq!(colors, [(Color::RED, 4), (Color::BLUE, 2)]);
+}
+
+#[test]
+fn can_reject_empty() {
+    let error = |input: &str| {
+        let error = input.parse::<Game>().unwrap_err();
+        (error.error, error.span.column)
+    };
+    let empty = ParseError::ParseReach(ParseReachError::Empty);
+    assert_eq!(error("Game 1: "), (empty.clone(), 9));
+    assert_eq!(error("Game 1: 1 red; ; 2 blue"), (empty, 16));
+    assert_eq!(
+        "".parse::<Reach>().unwrap_err().error,
+        ParseReachError::Empty
+    );
+}
+
+#[cfg(test)]
+fn reach_strategy() -> impl proptest::strategy::Strategy<Value = Reach> {
+    use proptest::prelude::*;
+    let color = "[a-z]{1,8}".prop_map(|name| Color::new(&name));
+    prop::collection::vec((color, 0..1000usize), 1..5).prop_map(|colors| Reach { colors })
+}
+
+#[cfg(test)]
+fn game_strategy() -> impl proptest::strategy::Strategy<Value = Game> {
+    use proptest::prelude::*;
+    (any::<u64>(), prop::collection::vec(reach_strategy(), 1..8))
+        .prop_map(|(id, reaches)| Game { id, reaches })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn can_roundtrip_reach(reach in reach_strategy()) {
+        proptest::prop_assert_eq!(reach.to_string().parse::<Reach>().unwrap(), reach);
+    }
+
+    #[test]
+    fn can_roundtrip_game(game in game_strategy()) {
+        proptest::prop_assert_eq!(game.to_string().parse::<Game>().unwrap(), game);
+    }
+}
+
+#[test]
+fn can_find_minimum() {
+    let games = Day02::parse(include_str!("../sample.txt")).unwrap();
+    assert_eq!(
+        games[0].minimum().colors,
+        [(Color::RED, 4), (Color::GREEN, 2), (Color::BLUE, 6)].into()
+    );
+    assert_eq!(
+        games[2].minimum().colors,
+        [(Color::RED, 20), (Color::GREEN, 13), (Color::BLUE, 6)].into()
+    );
+    let powers: Vec<usize> = games.iter().map(|game| game.power().unwrap()).collect();
+    assert_eq!(powers, [48, 12, 1560, 630, 36]);
+}
+
+#[test]
+fn can_intern_colors() {
+    assert_eq!(Color::new("red"), Color::RED);
+    let yellow: Color = "yellow".parse().unwrap();
+    assert_eq!(yellow, Color::new("yellow"));
+    assert!(std::ptr::eq(yellow.name(), Color::new("yellow").name()));
+    assert_ne!(yellow, Color::RED);
+    assert_eq!(yellow.to_string(), "yellow");
+    assert_eq!("Red".parse::<Color>(), Err(InvalidColor("Red".into())));
+    assert_eq!("".parse::<Color>(), Err(InvalidColor("".into())));
+}
+
+#[test]
+fn can_handle_unknown_colors() {
+    let game: Game = "Game 1: 3 blue, 0 yellow; 2 red".parse().unwrap();
+    let yellow = Color::new("yellow");
+    assert_eq!(game.reaches[0].get(yellow), 0);
+    assert_eq!(game.minimum().get(yellow), 0);
+
+    let config = Config::puzzle();
+    assert!(!config.possible_game(&game));
+    let config = config.with_unknown(Unknown::Zero);
+    assert!(config.possible_game(&game));
+
+    let game: Game = "Game 2: 1 yellow, 2 red".parse().unwrap();
+    assert!(!config.possible_game(&game));
+    assert!(Config::new([(Color::RED, 2), (yellow, 1)]).possible_game(&game));
+}
+
+/// Parse the games, one on every line.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, Spanned<ParseError>> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|error: Spanned<ParseError>| error.within(input, line))
+        })
+        .collect()
+}
+
+/// Sum of the ids of the games, if it fits in 64 bits.
+pub fn sum_ids<'a>(games: impl IntoIterator<Item = &'a Game>) -> Result<u64, GamesError> {
+    games
+        .into_iter()
+        .try_fold(0u64, |sum, game| sum.checked_add(game.id))
+        .ok_or(GamesError::SumOverflow("game ids"))
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GamesError {
+    #[error(transparent)]
+    Parse(#[from] Spanned<ParseError>),
+    #[error("power of game {0} does not fit in 64 bits")]
+    PowerOverflow(u64),
+    #[error("sum of the {0} does not fit in 64 bits")]
+    SumOverflow(&'static str),
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Error = GamesError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_games(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        let config = Config::puzzle();
+        sum_ids(input.iter().filter(|game| config.possible_game(game)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        input.iter().try_fold(0u64, |sum, game| {
+            let power = game
+                .power()
+                .and_then(|power| u64::try_from(power).ok())
+                .ok_or(GamesError::PowerOverflow(game.id))?;
+            sum.checked_add(power)
+                .ok_or(GamesError::SumOverflow("powers"))
+        })
+    }
+}
+
+#[test]
+fn can_run() {
+    let input = Day02::parse(include_str!("../sample.txt")).unwrap();
+    assert_eq!(Day02::part1(&input).unwrap(), 8);
+    assert_eq!(Day02::part2(&input).unwrap(), 2286);
+}
+
+#[test]
+fn can_report_overflow() {
+    let big = usize::MAX;
+    let games = Day02::parse(&format!(
+        "Game 1: {big} red, 2 green, 1 blue\nGame 2: 1 red, 1 green, 1 blue"
+    ))
+    .unwrap();
+    assert_eq!(games[0].power(), None);
+    assert_eq!(Day02::part2(&games), Err(GamesError::PowerOverflow(1)));
+
+    let games = Day02::parse(&format!(
+        "Game 1: {big} red, 1 green, 1 blue\nGame 2: {big} red, 1 green, 1 blue"
+    ))
+    .unwrap();
+    assert_eq!(Day02::part2(&games), Err(GamesError::SumOverflow("powers")));
+
+    let games = Day02::parse(&format!(
+        "Game {}: 1 red\nGame {}: 1 red",
+        u64::MAX,
+        u64::MAX
+    ))
+    .unwrap();
+    assert_eq!(
+        Day02::part1(&games),
+        Err(GamesError::SumOverflow("game ids"))
+    );
+}
@@ -1,148 +1,170 @@
-use std::{
-    collections::BTreeMap,
-    io::{stdin, BufRead, BufReader},
-    num::ParseIntError,
-    str::FromStr,
+use aoc_common::{input::read_stdin, Solution};
+use clap::{Parser, Subcommand, ValueEnum};
+use day02::{
+    infer::infer, parse_games, query::Query, span::Spanned, sum_ids, Config, Day02, Game, Unknown,
 };
-use strum::EnumString;
-use thiserror::Error;
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Color {
-    Blue,
-    Red,
-    Green,
-}
-
-struct Game {
-    id: u64,
-    reaches: Vec<Reach>,
-}
+use std::{fmt::Display, path::PathBuf, process::exit, str::FromStr};
 
-#[derive(Error, Debug)]
-enum ParseError {
-    #[error("missing game delimiter")]
-    MissingGameDelimiter,
-    #[error("missing game number")]
-    MissingGameNumber,
-    #[error(transparent)]
-    ParseGameNumber(#[from] ParseIntError),
-    #[error(transparent)]
-    ParseReach(#[from] ParseReachError),
+/// How to treat colors that are not in the bag.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Policy {
+    Reject,
+    Zero,
 }
 
-impl FromStr for Game {
-    type Err = ParseError;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        use ParseError::*;
-
-        let Some((game, reaches)) = input.split_once(": ") else {
-            return Err(MissingGameDelimiter);
-        };
-
-        let id: u64 = game.split(" ").nth(1).ok_or(MissingGameNumber)?.parse()?;
-
-        let reaches = reaches
-            .trim()
-            .split("; ")
-            .map(Reach::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(Game { id, reaches })
+impl From<Policy> for Unknown {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::Reject => Unknown::Reject,
+            Policy::Zero => Unknown::Zero,
+        }
     }
 }
 
-#[derive(Error, Debug)]
-enum ParseReachError {
-    #[error("invalid reach")]
-    Invalid,
-    #[error("invalid number")]
-    InvalidNumber(#[from] ParseIntError),
-    #[error("invalid color")]
-    InvalidColor(#[from] strum::ParseError),
+/// Parse an argument, showing where it is invalid.
+fn spanned<T, E>(input: &str) -> Result<T, String>
+where
+    T: FromStr<Err = Spanned<E>>,
+    E: Display,
+{
+    input
+        .parse()
+        .map_err(|error: Spanned<E>| format!("{error}\n{}", error.snippet().trim_end()))
 }
 
-struct Reach {
-    colors: BTreeMap<Color, usize>,
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print the id of every game matching a query, like
+    /// `max(red) > 10 and max(blue) <= 3`.
+    ///
+    /// Queries compare numbers, `id`, the number of `reaches` and the `max`,
+    /// `min` or `sum` of a color over the reaches, combined with `and`, `or`,
+    /// `not` and parentheses.
+    Query {
+        /// Query to match the games with.
+        #[arg(value_parser = spanned::<Query, _>)]
+        query: Query,
+    },
 }
 
-impl FromStr for Reach {
-    type Err = ParseReachError;
-
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        use ParseReachError::*;
-        let mut colors = BTreeMap::new();
-        for color in input.split(", ") {
-            let Some((number, color)) = color.split_once(" ") else {
-                return Err(Invalid);
-            };
-
-            let number: usize = number.parse()?;
-            let color: Color = color.parse()?;
-            let entry = colors.entry(color).or_default();
-            *entry += number;
-        }
-        Ok(Reach { colors })
-    }
+#[derive(Parser, Debug)]
+#[command(about = "Solve day 2, reading the input from standard input")]
+struct Options {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Contents of the bag for part one, like `red=12,green=13,blue=14`.
+    #[arg(long, value_parser = spanned::<Config, _>)]
+    bag: Option<Config>,
+
+    /// Load the contents of the bag from a TOML or JSON file instead, mapping
+    /// every color to its count.
+    #[arg(long, conflicts_with = "bag")]
+    bag_file: Option<PathBuf>,
+
+    /// How to treat colors that are not in the bag.
+    #[arg(long, value_enum, default_value = "reject")]
+    unknown: Policy,
+
+    /// Print the id of every game that is possible with the bag.
+    #[arg(long)]
+    possible: bool,
+
+    /// Estimate the contents of the bag from the games instead, assuming that
+    /// all games were played with the same bag.
+    #[arg(long)]
+    infer: bool,
+
+    /// Largest number of cubes of one color the bag may contain, when
+    /// estimating its contents.
+    #[arg(long, default_value_t = 100)]
+    limit: usize,
+
+    /// Confidence of the intervals, when estimating the contents of the bag.
+    #[arg(long, default_value_t = 0.95)]
+    confidence: f64,
 }
 
-struct Config {
-    colors: BTreeMap<Color, usize>,
-}
+fn main() {
+    let options = Options::parse();
+    let bag = match (options.bag, &options.bag_file) {
+        (Some(bag), _) => bag,
+        (None, Some(path)) => Config::load(path).unwrap_or_else(|error| {
+            eprintln!("error: {error}");
+            exit(1);
+        }),
+        (None, None) => Config::puzzle(),
+    };
+    let bag = bag.with_unknown(options.unknown.into());
+
+    let games = match read_stdin() {
+        Ok(input) => parse_games(&input).unwrap_or_else(|error| {
+            eprint!("error: {error}\n{}", error.snippet());
+            exit(1);
+        }),
+        Err(error) => {
+            eprintln!("error: reading input: {error}");
+            exit(1);
+        }
+    };
 
-impl Config {
-    pub fn possible_reach(&self, reach: &Reach) -> bool {
-        for (color, count) in reach.colors.iter() {
-            let Some(possible) = self.colors.get(color) else {
-                return false;
-            };
+    if let Some(Command::Query { query }) = &options.command {
+        for game in games.iter().filter(|game| query.matches(game)) {
+            println!("{}", game.id());
+        }
+        return;
+    }
 
-            if count > possible {
-                return false;
+    if options.infer {
+        let reaches = games.iter().flat_map(|game| game.reaches());
+        let estimates = infer(reaches, options.limit, options.confidence).unwrap_or_else(|error| {
+            eprintln!("error: {error}");
+            exit(1);
+        });
+        for estimate in estimates {
+            println!(
+                "{}: at least {}, most likely {}, mean {:.1}, {}% interval {}..={}",
+                estimate.color,
+                estimate.minimum,
+                estimate.mode,
+                estimate.mean,
+                options.confidence * 100.0,
+                estimate.interval.0,
+                estimate.interval.1
+            );
+            if estimate.at_limit {
+                eprintln!(
+                    "warning: the {} estimate is not identifiable, it piles up at the limit of {} \
+                     and depends on --limit rather than on the games",
+                    estimate.color, options.limit
+                );
             }
         }
-
-        true
+        return;
     }
 
-    pub fn possible_game(&self, game: &Game) -> bool {
-        game.reaches.iter().all(|reach| self.possible_reach(reach))
+    let possible: Vec<&Game> = games
+        .iter()
+        .filter(|game| bag.possible_game(game))
+        .collect();
+    if options.possible {
+        for game in &possible {
+            println!("{}", game.id());
+        }
     }
-}
 
-#[test]
-fn can_parse() {
-    let game: Game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
-        .parse()
-        .unwrap();
-    assert_eq!(game.id, 1);
-    assert_eq!(game.reaches.len(), 3);
-    assert_eq!(
-        game.reaches[0].colors,
-        [(Color::Blue, 3), (Color::Red, 4),].into()
-    );
-    assert_eq!(
-        game.reaches[1].colors,
-        [(Color::Red, 1), (Color::Green, 2), (Color::Blue, 6),].into()
-    );
-    assert_eq!(game.reaches[2].colors, [(Color::Green, 2),].into());
-}
-
-fn main() {
-    let config = Config {
-        colors: [(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)].into(),
-    };
-
-    let input = BufReader::new(stdin());
-
-    let sum: u64 = input
-        .lines()
-        .map(|line| Game::from_str(&line.unwrap()).unwrap())
-        .filter(|game| config.possible_game(game))
-        .map(|game| game.id)
-        .sum();
-
-    println!("{sum}");
+    match sum_ids(possible) {
+        Ok(part1) => println!("part1 {part1}"),
+        Err(error) => {
+            eprintln!("error: {error}");
+            exit(1);
+        }
+    }
+    match Day02::part2(&games) {
+        Ok(part2) => println!("part2 {part2}"),
+        Err(error) => {
+            eprintln!("error: {error}");
+            exit(1);
+        }
+    }
 }
@@ -0,0 +1,436 @@
+//! Predicates over games, such as `max(red) > 10 and max(blue) <= 3`.
+//!
+//! A query compares values with `<`, `<=`, `>`, `>=`, `==` and `!=`, and
+//! combines comparisons with `and`, `or`, `not` and parentheses. Values are
+//! numbers, the game `id`, the number of `reaches`, or `max`, `min` or `sum`
+//! of the cubes of a color over all reaches of the game.
+
+use crate::{span::Spanned, Color, Game, InvalidColor};
+use std::{num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    #[error("unexpected character {0:?}")]
+    Character(char),
+    #[error("unexpected end of query, expected {0}")]
+    End(&'static str),
+    #[error("unexpected {token:?}, expected {expected}")]
+    Unexpected {
+        token: String,
+        expected: &'static str,
+    },
+    #[error("unknown function {0:?}")]
+    Function(String),
+    #[error("invalid number: {0}")]
+    Number(#[from] ParseIntError),
+    #[error(transparent)]
+    Color(#[from] InvalidColor),
+    #[error("query is nested more than {0} levels deep")]
+    Nesting(usize),
+}
+
+/// Aggregate of the cubes of one color over the reaches of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Max,
+    Min,
+    Sum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    Number(u64),
+    Id,
+    Reaches,
+    Aggregate(Aggregate, Color),
+}
+
+impl Value {
+    pub fn evaluate(&self, game: &Game) -> u64 {
+        match self {
+            Value::Number(number) => *number,
+            Value::Id => game.id(),
+            Value::Reaches => game.reaches().len() as u64,
+            Value::Aggregate(aggregate, color) => {
+                let counts = game.reaches().iter().map(|reach| reach.get(*color) as u64);
+                match aggregate {
+                    Aggregate::Max => counts.max().unwrap_or(0),
+                    Aggregate::Min => counts.min().unwrap_or(0),
+                    Aggregate::Sum => counts.fold(0, u64::saturating_add),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compare {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Compare {
+    fn apply(&self, left: u64, right: u64) -> bool {
+        match self {
+            Compare::Less => left < right,
+            Compare::LessEqual => left <= right,
+            Compare::Greater => left > right,
+            Compare::GreaterEqual => left >= right,
+            Compare::Equal => left == right,
+            Compare::NotEqual => left != right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    Compare(Value, Compare, Value),
+    Not(Box<Query>),
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+}
+
+impl Query {
+    pub fn matches(&self, game: &Game) -> bool {
+        match self {
+            Query::Compare(left, compare, right) => {
+                compare.apply(left.evaluate(game), right.evaluate(game))
+            }
+            Query::Not(query) => !query.matches(game),
+            Query::And(left, right) => left.matches(game) && right.matches(game),
+            Query::Or(left, right) => left.matches(game) || right.matches(game),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, Spanned<QueryError>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    Number(&'a str),
+    Open,
+    Close,
+    Compare(Compare),
+}
+
+/// Split the query into tokens and the text they were read from.
+fn tokenize(input: &str) -> Result<Vec<(&str, Token<'_>)>> {
+    let mut tokens = vec![];
+    let mut rest = input.char_indices().peekable();
+    while let Some((offset, character)) = rest.next() {
+        let token = match character {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '<' | '>' | '=' | '!' => {
+                let equal = rest.next_if(|(_, next)| *next == '=').is_some();
+                Token::Compare(match (character, equal) {
+                    ('<', false) => Compare::Less,
+                    ('<', true) => Compare::LessEqual,
+                    ('>', false) => Compare::Greater,
+                    ('>', true) => Compare::GreaterEqual,
+                    ('=', true) => Compare::Equal,
+                    ('!', true) => Compare::NotEqual,
+                    _ => {
+                        let text = &input[offset..offset + character.len_utf8()];
+                        return Err(Spanned::new(QueryError::Character(character), input, text));
+                    }
+                })
+            }
+            c if c.is_ascii_digit() || c.is_alphabetic() || c == '_' => {
+                let mut end = offset + c.len_utf8();
+                while let Some((next, c)) =
+                    rest.next_if(|(_, c)| c.is_ascii_digit() || c.is_alphabetic() || *c == '_')
+                {
+                    end = next + c.len_utf8();
+                }
+                let word = &input[offset..end];
+                match character.is_ascii_digit() {
+                    true => Token::Number(word),
+                    false => Token::Word(word),
+                }
+            }
+            _ => {
+                let text = &input[offset..offset + character.len_utf8()];
+                return Err(Spanned::new(QueryError::Character(character), input, text));
+            }
+        };
+        let end = rest.peek().map_or(input.len(), |(end, _)| *end);
+        tokens.push((input[offset..end].trim_end(), token));
+    }
+    Ok(tokens)
+}
+
+/// Deepest nesting of `not` and parentheses, to bound the recursion.
+const MAX_DEPTH: usize = 64;
+
+/// Recursive descent parser over the tokens of a query.
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<(&'a str, Token<'a>)>,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).map(|(_, token)| *token)
+    }
+
+    fn next(&mut self, expected: &'static str) -> Result<(&'a str, Token<'a>)> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .copied()
+            .ok_or_else(|| Spanned::end(QueryError::End(expected), self.input))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn error(&self, error: QueryError, text: &str) -> Spanned<QueryError> {
+        Spanned::new(error, self.input, text)
+    }
+
+    fn unexpected(&self, text: &str, expected: &'static str) -> Spanned<QueryError> {
+        let error = QueryError::Unexpected {
+            token: text.into(),
+            expected,
+        };
+        self.error(error, text)
+    }
+
+    fn expect(&mut self, token: Token, expected: &'static str) -> Result<()> {
+        match self.next(expected)? {
+            (_, next) if next == token => Ok(()),
+            (text, _) => Err(self.unexpected(text, expected)),
+        }
+    }
+
+    /// Parse a query nested in `text`, one level deeper.
+    fn nested(
+        &mut self,
+        text: &str,
+        parse: impl FnOnce(&mut Self) -> Result<Query>,
+    ) -> Result<Query> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(QueryError::Nesting(MAX_DEPTH), text));
+        }
+        self.depth += 1;
+        let query = parse(self);
+        self.depth -= 1;
+        query
+    }
+
+    fn or(&mut self) -> Result<Query> {
+        let mut query = self.and()?;
+        while self.peek() == Some(Token::Word("or")) {
+            self.position += 1;
+            query = Query::Or(query.into(), self.and()?.into());
+        }
+        Ok(query)
+    }
+
+    fn and(&mut self) -> Result<Query> {
+        let mut query = self.not()?;
+        while self.peek() == Some(Token::Word("and")) {
+            self.position += 1;
+            query = Query::And(query.into(), self.not()?.into());
+        }
+        Ok(query)
+    }
+
+    fn not(&mut self) -> Result<Query> {
+        match self.tokens.get(self.position).copied() {
+            Some((text, Token::Word("not"))) => {
+                self.position += 1;
+                self.nested(text, |parser| Ok(Query::Not(parser.not()?.into())))
+            }
+            Some((text, Token::Open)) => {
+                self.position += 1;
+                self.nested(text, |parser| {
+                    let query = parser.or()?;
+                    parser.expect(Token::Close, "`)`")?;
+                    Ok(query)
+                })
+            }
+            _ => self.compare(),
+        }
+    }
+
+    fn compare(&mut self) -> Result<Query> {
+        let left = self.value()?;
+        let compare = match self.next("comparison")? {
+            (_, Token::Compare(compare)) => compare,
+            (text, _) => return Err(self.unexpected(text, "comparison")),
+        };
+        let right = self.value()?;
+        Ok(Query::Compare(left, compare, right))
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        const EXPECTED: &str = "number, `id`, `reaches` or aggregate";
+        let word = match self.next(EXPECTED)? {
+            (number, Token::Number(_)) => {
+                return number
+                    .parse()
+                    .map(Value::Number)
+                    .map_err(|error| self.error(QueryError::Number(error), number))
+            }
+            (_, Token::Word("id")) => return Ok(Value::Id),
+            (_, Token::Word("reaches")) => return Ok(Value::Reaches),
+            (word, Token::Word(_)) => word,
+            (text, _) => return Err(self.unexpected(text, EXPECTED)),
+        };
+
+        let aggregate = match word {
+            "max" => Aggregate::Max,
+            "min" => Aggregate::Min,
+            "sum" => Aggregate::Sum,
+            _ => return Err(self.error(QueryError::Function(word.into()), word)),
+        };
+        self.expect(Token::Open, "`(`")?;
+        let color = match self.next("color")? {
+            (color, Token::Word(_)) => color
+                .parse()
+                .map_err(|error| self.error(QueryError::Color(error), color))?,
+            (text, _) => return Err(self.unexpected(text, "color")),
+        };
+        self.expect(Token::Close, "`)`")?;
+        Ok(Value::Aggregate(aggregate, color))
+    }
+}
+
+impl FromStr for Query {
+    type Err = Spanned<QueryError>;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut parser = Parser {
+            input,
+            tokens: tokenize(input)?,
+            position: 0,
+            depth: 0,
+        };
+        let query = parser.or()?;
+        match parser.tokens.get(parser.position) {
+            Some((text, _)) => Err(parser.unexpected(text, "`and` or `or`")),
+            None => Ok(query),
+        }
+    }
+}
+
+#[test]
+fn can_parse_query() {
+    let query: Query = "max(red) > 10 and not (max(blue) > 3)".parse().unwrap();
+    assert_eq!(
+        query,
+        Query::And(
+            Query::Compare(
+                Value::Aggregate(Aggregate::Max, Color::RED),
+                Compare::Greater,
+                Value::Number(10)
+            )
+            .into(),
+            Query::Not(
+                Query::Compare(
+                    Value::Aggregate(Aggregate::Max, Color::BLUE),
+                    Compare::Greater,
+                    Value::Number(3)
+                )
+                .into()
+            )
+            .into()
+        )
+    );
+
+    // `and` binds tighter than `or`
+    let query: Query = "id == 1 or id == 2 and reaches < 2".parse().unwrap();
+    assert!(matches!(query, Query::Or(_, right) if matches!(*right, Query::And(_, _))));
+
+    let error = |input: &str| {
+        let error = input.parse::<Query>().unwrap_err();
+        (error.error, error.span.offset, error.span.text)
+    };
+    assert_eq!(
+        error("max(red) > "),
+        (
+            QueryError::End("number, `id`, `reaches` or aggregate"),
+            11,
+            "".into()
+        )
+    );
+    assert_eq!(
+        error("avg(red) > 1"),
+        (QueryError::Function("avg".into()), 0, "avg".into())
+    );
+    assert_eq!(error("id = 1"), (QueryError::Character('='), 3, "=".into()));
+    assert_eq!(
+        error("id > 1 id"),
+        (
+            QueryError::Unexpected {
+                token: "id".into(),
+                expected: "`and` or `or`"
+            },
+            7,
+            "id".into()
+        )
+    );
+    assert_eq!(error("max(red) >= 10 or sum(Red) > 1").1, 22);
+    assert_eq!(
+        error("id <= 99999999999999999999").2,
+        "99999999999999999999"
+    );
+
+    let nested = |prefix: &str, suffix: &str, depth| {
+        format!("{}id == 1{}", prefix.repeat(depth), suffix.repeat(depth))
+    };
+    assert!(nested("not ", "", MAX_DEPTH).parse::<Query>().is_ok());
+    assert!(nested("(", ")", MAX_DEPTH).parse::<Query>().is_ok());
+    assert_eq!(
+        error(&nested("not ", "", 100_000)),
+        (QueryError::Nesting(MAX_DEPTH), 4 * MAX_DEPTH, "not".into())
+    );
+    assert_eq!(
+        error(&nested("(", ")", 100_000)),
+        (QueryError::Nesting(MAX_DEPTH), MAX_DEPTH, "(".into())
+    );
+
+    let error = "max(red) >= 1 and min(green) ! 2"
+        .parse::<Query>()
+        .unwrap_err();
+    assert_eq!(
+        error.snippet(),
+        "  |\n1 | max(red) >= 1 and min(green) ! 2\n  |                              ^\n"
+    );
+}
+
+#[test]
+fn can_query_games() {
+    let games: Vec<Game> = aoc_common::split::parse_lines(include_str!("../sample.txt")).unwrap();
+    let ids = |query: &str| -> Vec<u64> {
+        let query: Query = query.parse().unwrap();
+        games
+            .iter()
+            .filter(|game| query.matches(game))
+            .map(Game::id)
+            .collect()
+    };
+
+    assert_eq!(ids("max(red) > 10 and max(blue) <= 6"), [3]);
+    assert_eq!(ids("reaches == 2"), [5]);
+    assert_eq!(ids("min(green) == 0"), [1]);
+    assert_eq!(ids("sum(blue) >= 10 or id == 1"), [1, 3, 4]);
+    assert_eq!(
+        ids("not (max(red) <= 12 and max(green) <= 13 and max(blue) <= 14)"),
+        [3, 4]
+    );
+
+    let game: Game = format!("Game 1: {} red; 2 red", u64::MAX).parse().unwrap();
+    let sum = Value::Aggregate(Aggregate::Sum, Color::RED);
+    assert_eq!(sum.evaluate(&game), u64::MAX);
+}
@@ -0,0 +1,155 @@
+//! Locations of parse errors, rendered as source snippets.
+
+use std::fmt::{Debug, Display};
+
+/// Part of the input that an error refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the start in the input.
+    pub offset: usize,
+    /// Line of the start, starting at 1.
+    pub line: usize,
+    /// Column of the start in characters, starting at 1.
+    pub column: usize,
+    /// Offending text, empty if something is missing.
+    pub text: String,
+    /// Whole line containing the start.
+    pub context: String,
+}
+
+/// Byte offset of `part` in `source`, of which it must be a slice.
+fn offset_of(source: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+    assert!(offset <= source.len(), "part is not a slice of source");
+    offset
+}
+
+impl Span {
+    /// Span of the `length` bytes at `offset` in `source`.
+    pub fn at(source: &str, offset: usize, length: usize) -> Self {
+        let start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |index| offset + index);
+        Span {
+            offset,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[start..offset].chars().count() + 1,
+            text: source[offset..offset + length].into(),
+            context: source[start..end].trim_end_matches('\r').into(),
+        }
+    }
+
+    /// Span of `part`, which must be a slice of `source`.
+    pub fn of(source: &str, part: &str) -> Self {
+        Span::at(source, offset_of(source, part), part.len())
+    }
+
+    /// Span of the end of `source`, for things that are missing.
+    pub fn end(source: &str) -> Self {
+        Span::at(source, source.len(), 0)
+    }
+
+    /// Render the line with carets under the offending text.
+    pub fn snippet(&self) -> String {
+        let line = self.line.to_string();
+        let margin = " ".repeat(line.len());
+        let indent: String = self
+            .context
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = self
+            .text
+            .chars()
+            .count()
+            .min(self.context.chars().count().saturating_sub(self.column - 1))
+            .max(1);
+        format!(
+            "{margin} |\n{line} | {}\n{margin} | {indent}{}\n",
+            self.context,
+            "^".repeat(width)
+        )
+    }
+}
+
+/// Error along with the part of the input it refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<E> {
+    pub error: E,
+    pub span: Span,
+}
+
+impl<E> Spanned<E> {
+    /// Error about `part`, which must be a slice of `source`.
+    pub fn new(error: E, source: &str, part: &str) -> Self {
+        Spanned {
+            error,
+            span: Span::of(source, part),
+        }
+    }
+
+    /// Error about something missing at the end of `source`.
+    pub fn end(error: E, source: &str) -> Self {
+        Spanned {
+            error,
+            span: Span::end(source),
+        }
+    }
+
+    /// Locate an error from parsing `part` in the larger `source` it is a
+    /// slice of.
+    pub fn within(self, source: &str, part: &str) -> Self {
+        let offset = offset_of(source, part) + self.span.offset;
+        Spanned {
+            error: self.error,
+            span: Span::at(source, offset, self.span.text.len()),
+        }
+    }
+
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> Spanned<F> {
+        Spanned {
+            error: f(self.error),
+            span: self.span,
+        }
+    }
+
+    /// Render the line with carets under the offending text.
+    pub fn snippet(&self) -> String {
+        self.span.snippet()
+    }
+}
+
+impl<E: Display> Display for Spanned<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.error, self.span.line, self.span.column
+        )
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for Spanned<E> {}
+
+#[test]
+fn can_locate() {
+    let source = "first\nsecond línea\r\nthird";
+    let span = Span::of(source, &source[13..19]);
+    assert_eq!(span.offset, 13);
+    assert_eq!(span.line, 2);
+    assert_eq!(span.column, 8);
+    assert_eq!(span.text, "línea");
+    assert_eq!(span.context, "second línea");
+    assert_eq!(span.snippet(), "  |\n2 | second línea\n  |        ^^^^^\n");
+
+    let span = Span::end(source);
+    assert_eq!((span.line, span.column), (3, 6));
+    assert_eq!(span.snippet(), "  |\n3 | third\n  |      ^\n");
+
+    let line = &source[6..19];
+    let error = Spanned::new("bad", line, &line[7..]).within(source, line);
+    assert_eq!(error.span, Span::of(source, &source[13..19]));
+    assert_eq!(error.to_string(), "bad at line 2, column 8");
+}
@@ -0,0 +1,80 @@
+use aoc_common::{Grid, Point, Solution};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day03::{Day03, Entity, Field, Schematic};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::BTreeMap, hint::black_box, str::FromStr};
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// Generate a square schematic with random numbers and symbols.
+fn generate(size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut output = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        let mut line = Vec::with_capacity(size);
+        while line.len() < size {
+            match rng.gen_range(0..10) {
+                0..=1 => {
+                    let digits = rng.gen_range(1..=3).min(size - line.len());
+                    line.extend((0..digits).map(|_| b'0' + rng.gen_range(0..10)));
+                    if line.len() < size {
+                        line.push(b'.');
+                    }
+                }
+                2 => line.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())]),
+                _ => line.push(b'.'),
+            }
+        }
+        output.push_str(std::str::from_utf8(&line).unwrap());
+        output.push('\n');
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    let inputs = [
+        ("sample", include_str!("../src/input1.txt").to_string()),
+        ("generated", generate(1000)),
+    ];
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day03/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day03::parse(input).unwrap();
+        group.bench_function("Schematic::from_str", |b| {
+            b.iter(|| <Schematic>::from_str(black_box(input)))
+        });
+        group.bench_function("part1", |b| b.iter(|| Day03::part1(black_box(&parsed))));
+        group.bench_function("part2", |b| b.iter(|| Day03::part2(black_box(&parsed))));
+        group.finish();
+    }
+}
+
+/// Compare the dense and sparse storage of the schematic on large inputs.
+fn bench_field<F: Field>(c: &mut Criterion, name: &str) {
+    for size in [1000, 2000] {
+        let input = generate(size);
+        let mut group = c.benchmark_group(format!("day03/{name}/{size}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.sample_size(10);
+        let parsed: Schematic<F> = input.parse().unwrap();
+        group.bench_function("from_str", |b| {
+            b.iter(|| black_box(&input).parse::<Schematic<F>>())
+        });
+        group.bench_function("part_numbers", |b| {
+            b.iter(|| black_box(&parsed).part_numbers())
+        });
+        group.bench_function("gear_ratios", |b| {
+            b.iter(|| black_box(&parsed).gear_ratios())
+        });
+        group.finish();
+    }
+}
+
+fn bench_fields(c: &mut Criterion) {
+    bench_field::<Grid<Option<Entity>>>(c, "dense");
+    bench_field::<BTreeMap<Point, Entity>>(c, "sparse");
+}
+
+criterion_group!(benches, bench, bench_fields);
+criterion_main!(benches);
@@ -0,0 +1,398 @@
+use aoc_common::{Grid, Point, Solution};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+    ops::Range,
+    str::FromStr,
+};
+use thiserror::Error;
+
+/// Entity that can be on the schematic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entity {
+    /// Symbol
+    Symbol(char),
+    /// Number (index into numbers array)
+    Number(usize),
+}
+
+/// Storage for the entities on a schematic.
+pub trait Field: Debug + Clone {
+    /// Create empty storage for a schematic of the given size.
+    fn new(width: usize, height: usize) -> Self;
+
+    fn get(&self, point: Point) -> Option<Entity>;
+
+    fn insert(&mut self, point: Point, entity: Entity);
+
+    /// Iterate over all entities along with their position.
+    fn entities(&self) -> impl Iterator<Item = (Point, Entity)> + '_;
+}
+
+/// Sparse storage, only keeping the cells that have an entity.
+impl Field for BTreeMap<Point, Entity> {
+    fn new(_width: usize, _height: usize) -> Self {
+        BTreeMap::new()
+    }
+
+    fn get(&self, point: Point) -> Option<Entity> {
+        BTreeMap::get(self, &point).copied()
+    }
+
+    fn insert(&mut self, point: Point, entity: Entity) {
+        BTreeMap::insert(self, point, entity);
+    }
+
+    fn entities(&self) -> impl Iterator<Item = (Point, Entity)> + '_ {
+        self.iter().map(|(point, entity)| (*point, *entity))
+    }
+}
+
+/// Dense storage, keeping every cell in row-major order.
+impl Field for Grid<Option<Entity>> {
+    fn new(width: usize, height: usize) -> Self {
+        Grid::new(width, height, None)
+    }
+
+    fn get(&self, point: Point) -> Option<Entity> {
+        Grid::get(self, point).copied().flatten()
+    }
+
+    fn insert(&mut self, point: Point, entity: Entity) {
+        if let Some(cell) = self.get_mut(point) {
+            *cell = Some(entity);
+        }
+    }
+
+    fn entities(&self) -> impl Iterator<Item = (Point, Entity)> + '_ {
+        self.iter()
+            .filter_map(|(point, entity)| entity.map(|entity| (point, entity)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic<F = Grid<Option<Entity>>> {
+    numbers: Vec<u64>,
+    field: F,
+}
+
+/// Schematic using sparse storage.
+pub type SparseSchematic = Schematic<BTreeMap<Point, Entity>>;
+
+impl<F: Field> Schematic<F> {
+    pub fn get<T: TryInto<usize>>(&self, point: Point<T>) -> Option<Entity> {
+        let point = Point(point.0.try_into().ok()?, point.1.try_into().ok()?);
+        self.field.get(point)
+    }
+
+    pub fn get_symbol<T: TryInto<usize>>(&self, point: Point<T>) -> Option<char> {
+        match self.get(point) {
+            Some(Entity::Symbol(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn get_number<T: TryInto<usize>>(&self, point: Point<T>) -> Option<u64> {
+        match self.get(point) {
+            Some(Entity::Number(o)) => self.numbers.get(o).copied(),
+            _ => None,
+        }
+    }
+
+    fn part_number_offsets(&self) -> BTreeSet<usize> {
+        let mut set = BTreeSet::default();
+        for (pos, entity) in self.field.entities() {
+            let index = match entity {
+                Entity::Number(offset) => offset,
+                _ => continue,
+            };
+
+            let touching = pos.touching().any(|pos| self.get_symbol(pos).is_some());
+
+            if touching {
+                set.insert(index);
+            }
+        }
+
+        set
+    }
+
+    pub fn part_numbers(&self) -> Vec<u64> {
+        self.part_number_offsets()
+            .into_iter()
+            .flat_map(|offset| self.numbers.get(offset))
+            .copied()
+            .collect()
+    }
+
+    /// Sum of the part numbers.
+    pub fn part_number_sum(&self) -> Result<u64, SchematicError> {
+        self.part_numbers()
+            .into_iter()
+            .try_fold(0u64, |sum, number| sum.checked_add(number))
+            .ok_or(SchematicError::SumOverflow("part numbers"))
+    }
+
+    pub fn gear_ratios(&self) -> Result<Vec<u64>, SchematicError> {
+        let mut ratios = vec![];
+        for (pos, entity) in self.field.entities() {
+            if entity != Entity::Symbol('*') {
+                continue;
+            }
+
+            let touching: BTreeSet<usize> = pos
+                .touching()
+                .filter_map(|pos| match self.get(pos) {
+                    Some(Entity::Number(offset)) => Some(offset),
+                    _ => None,
+                })
+                .collect();
+
+            if touching.len() == 2 {
+                let ratio = touching
+                    .iter()
+                    .filter_map(|o| self.numbers.get(*o).copied())
+                    .try_fold(1u64, |ratio, number| ratio.checked_mul(number))
+                    .ok_or(SchematicError::RatioOverflow {
+                        line: pos.1 + 1,
+                        column: pos.0 + 1,
+                    })?;
+                ratios.push(ratio);
+            }
+        }
+
+        Ok(ratios)
+    }
+
+    pub fn gear_ratio_sums(&self) -> Result<u64, SchematicError> {
+        self.gear_ratios()?
+            .into_iter()
+            .try_fold(0u64, |sum, ratio| sum.checked_add(ratio))
+            .ok_or(SchematicError::SumOverflow("gear ratios"))
+    }
+}
+
+/// Error parsing a schematic.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SchematicError {
+    #[error("number {digits} at line {line}, column {column} does not fit in 64 bits")]
+    Overflow {
+        line: usize,
+        column: usize,
+        digits: String,
+    },
+    #[error("gear ratio of the gear at line {line}, column {column} does not fit in 64 bits")]
+    RatioOverflow { line: usize, column: usize },
+    #[error("sum of the {0} does not fit in 64 bits")]
+    SumOverflow(&'static str),
+}
+
+/// Find the runs of ASCII digits in a line, along with their columns and
+/// value, which is `None` if it does not fit in a `u64`.
+pub fn numbers(line: &str) -> impl Iterator<Item = (Range<usize>, Option<u64>)> + '_ {
+    let mut chars = line.chars().enumerate().peekable();
+    std::iter::from_fn(move || {
+        let (start, digit) = chars.find_map(|(x, c)| Some((x, c.to_digit(10)?)))?;
+        let mut range = start..start + 1;
+        let mut value = Some(u64::from(digit));
+        while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+            let digit = u64::from(c as u8 - b'0');
+            range.end += 1;
+            value = value.and_then(|value| value.checked_mul(10)?.checked_add(digit));
+        }
+        Some((range, value))
+    })
+}
+
+impl<F: Field> FromStr for Schematic<F> {
+    type Err = SchematicError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let width = input.lines().map(|line| line.chars().count()).max();
+        let height = input.lines().count();
+        let mut schematic = Schematic {
+            numbers: vec![],
+            field: F::new(width.unwrap_or(0), height),
+        };
+        for (y, line) in input.lines().enumerate() {
+            // insert symbols
+            for (x, c) in line.chars().enumerate() {
+                if !matches!(c, '.' | '0'..='9') {
+                    schematic.field.insert(Point(x, y), Entity::Symbol(c));
+                }
+            }
+
+            // insert numbers
+            for (range, number) in numbers(line) {
+                let Some(number) = number else {
+                    return Err(SchematicError::Overflow {
+                        line: y + 1,
+                        column: range.start + 1,
+                        digits: line.chars().skip(range.start).take(range.len()).collect(),
+                    });
+                };
+                schematic.numbers.push(number);
+                let number = Entity::Number(schematic.numbers.len() - 1);
+                for x in range {
+                    schematic.field.insert(Point(x, y), number);
+                }
+            }
+        }
+
+        Ok(schematic)
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+    type Error = SchematicError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        input.part_number_sum()
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        input.gear_ratio_sums()
+    }
+}
+
+#[test]
+fn can_parse() {
+    let input = include_str!("input1.txt");
+    let schematic: Schematic = input.parse().unwrap();
+
+    println!("{schematic:?}");
+    assert_eq!(
+        schematic.numbers,
+        [467, 114, 35, 633, 617, 58, 592, 755, 664, 598,]
+    );
+
+    // symbols
+    assert_eq!(schematic.get_symbol(Point(3, 1)), Some('*'));
+    assert_eq!(schematic.get_symbol(Point(6, 3)), Some('#'));
+    assert_eq!(schematic.get_symbol(Point(3, 4)), Some('*'));
+    assert_eq!(schematic.get_symbol(Point(5, 5)), Some('+'));
+    assert_eq!(schematic.get_symbol(Point(3, 8)), Some('$'));
+    assert_eq!(schematic.get_symbol(Point(5, 8)), Some('*'));
+
+    // numbers
+    assert_eq!(schematic.get_number(Point(0, 0)), Some(467));
+    assert_eq!(schematic.get_number(Point(1, 0)), Some(467));
+    assert_eq!(schematic.get_number(Point(2, 0)), Some(467));
+    assert_eq!(schematic.get_number(Point(5, 0)), Some(114));
+    assert_eq!(schematic.get_number(Point(6, 0)), Some(114));
+    assert_eq!(schematic.get_number(Point(7, 0)), Some(114));
+    assert_eq!(schematic.get_number(Point(2, 2)), Some(35));
+    assert_eq!(schematic.get_number(Point(3, 2)), Some(35));
+}
+
+#[test]
+fn can_find_numbers() {
+    let found: Vec<_> = numbers("467..114..").collect();
+    assert_eq!(found, [(0..3, Some(467)), (5..8, Some(114))]);
+    let found: Vec<_> = numbers("*0.€12#9").collect();
+    assert_eq!(found, [(1..2, Some(0)), (4..6, Some(12)), (7..8, Some(9))]);
+    assert_eq!(numbers("...*..").count(), 0);
+    let found: Vec<_> = numbers("18446744073709551615.18446744073709551616").collect();
+    assert_eq!(found, [(0..20, Some(u64::MAX)), (21..41, None)]);
+}
+
+#[test]
+fn can_report_overflow() {
+    let error = "1.2\n..*99999999999999999999\n"
+        .parse::<Schematic>()
+        .unwrap_err();
+    assert_eq!(
+        error,
+        SchematicError::Overflow {
+            line: 2,
+            column: 4,
+            digits: "99999999999999999999".into()
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "number 99999999999999999999 at line 2, column 4 does not fit in 64 bits"
+    );
+
+    let input = Day03::parse("4294967296*4294967296\n").unwrap();
+    assert_eq!(Day03::part1(&input), Ok(8589934592));
+    let error = Day03::part2(&input).unwrap_err();
+    assert_eq!(
+        error,
+        SchematicError::RatioOverflow {
+            line: 1,
+            column: 11
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "gear ratio of the gear at line 1, column 11 does not fit in 64 bits"
+    );
+
+    let input = Day03::parse("18446744073709551615*18446744073709551615").unwrap();
+    assert_eq!(
+        Day03::part1(&input),
+        Err(SchematicError::SumOverflow("part numbers"))
+    );
+
+    let input = Day03::parse("4294967296*2147483648\n\n4294967296*2147483648").unwrap();
+    assert_eq!(input.gear_ratios().unwrap(), [1 << 63, 1 << 63]);
+    assert_eq!(
+        Day03::part2(&input),
+        Err(SchematicError::SumOverflow("gear ratios"))
+    );
+}
+
+#[test]
+fn can_solve() {
+    let input = include_str!("input1.txt");
+    let schematic: Schematic = input.parse().unwrap();
+    let numbers = schematic.part_numbers();
+    assert_eq!(numbers, [467, 35, 633, 617, 592, 755, 664, 598]);
+}
+
+#[test]
+fn can_solve2() {
+    let input = include_str!("input1.txt");
+    let schematic: Schematic = input.parse().unwrap();
+    assert_eq!(schematic.gear_ratios().unwrap(), [16345, 451490]);
+    assert_eq!(schematic.gear_ratio_sums().unwrap(), 467835);
+}
+
+#[test]
+fn can_use_sparse_field() {
+    let input = include_str!("input1.txt");
+    let dense: Schematic = input.parse().unwrap();
+    let sparse: SparseSchematic = input.parse().unwrap();
+    assert_eq!(sparse.numbers, dense.numbers);
+    assert_eq!(sparse.get_symbol(Point(3, 1)), Some('*'));
+    assert_eq!(sparse.get_number(Point(7, 0)), Some(114));
+    assert_eq!(sparse.get(Point(-1, 0)), None);
+    assert_eq!(sparse.part_numbers(), dense.part_numbers());
+    assert_eq!(sparse.gear_ratios(), dense.gear_ratios());
+
+    // lines may have different lengths
+    let schematic: Schematic = "1*\n2\n#..3".parse().unwrap();
+    assert_eq!(schematic.get_symbol(Point(1, 0)), Some('*'));
+    assert_eq!(schematic.get_symbol(Point(0, 2)), Some('#'));
+    assert_eq!(schematic.get(Point(1, 1)), None);
+    assert_eq!(schematic.get(Point(4, 0)), None);
+    assert_eq!(schematic.part_numbers(), [1, 2]);
+}
+
+#[test]
+fn can_run() {
+    let input = Day03::parse(include_str!("input1.txt")).unwrap();
+    assert_eq!(Day03::part1(&input).unwrap(), 4361);
+    assert_eq!(Day03::part2(&input).unwrap(), 467835);
+}
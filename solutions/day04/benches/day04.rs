@@ -0,0 +1,51 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day04::{Card, Day04};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{hint::black_box, str::FromStr};
+
+/// Generate cards with 10 winning and 25 selected numbers each.
+fn generate(cards: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(4);
+    let mut numbers = |count| {
+        (0..count)
+            .map(|_| format!("{:2}", rng.gen_range(1..100)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut output = String::new();
+    for number in 1..=cards {
+        let winning = numbers(10);
+        let selected = numbers(25);
+        output.push_str(&format!("Card {number}: {winning} | {selected}\n"));
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    let inputs = [
+        ("sample", include_str!("../src/input.txt").to_string()),
+        ("generated", generate(10_000)),
+    ];
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day04/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day04::parse(input).unwrap();
+        group.bench_function("parse", |b| b.iter(|| Day04::parse(black_box(input))));
+        group.bench_function("part1", |b| b.iter(|| Day04::part1(black_box(&parsed))));
+        group.finish();
+    }
+
+    let line = include_str!("../src/input.txt").lines().next().unwrap();
+    let card = Card::from_str(line).unwrap();
+    c.bench_function("day04/Card::from_str", |b| {
+        b.iter(|| Card::from_str(black_box(line)))
+    });
+    c.bench_function("day04/Card::points", |b| {
+        b.iter(|| black_box(&card).points())
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
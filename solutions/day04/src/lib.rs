@@ -0,0 +1,118 @@
+use aoc_common::{
+    split::{numbers, parse_lines},
+    Solution, Unsolved,
+};
+use std::{collections::BTreeSet, num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("missing colon separator")]
+    MissingSeparator,
+    #[error("missing card separator space")]
+    MissingCardSpace,
+    #[error("missing numbers separator")]
+    MissingNumbersSeparator,
+    #[error(transparent)]
+    Parse(#[from] ParseIntError),
+}
+
+pub struct Card {
+    pub number: u64,
+    pub winning: Vec<u64>,
+    pub selected: Vec<u64>,
+}
+
+impl Card {
+    pub fn winning_selected(&self) -> usize {
+        let winning: BTreeSet<_> = self.winning.iter().copied().collect();
+        self.selected
+            .iter()
+            .filter(|num| winning.contains(num))
+            .count()
+    }
+
+    pub fn points(&self) -> u64 {
+        match self.winning_selected() {
+            0 => 0,
+            other => 1 << (other - 1),
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (card, rest) = input.split_once(": ").ok_or(ParseError::MissingSeparator)?;
+        let number = card
+            .split_whitespace()
+            .nth(1)
+            .ok_or(ParseError::MissingCardSpace)?;
+        let number = number.parse()?;
+        let (winning, selected) = rest
+            .split_once(" | ")
+            .ok_or(ParseError::MissingNumbersSeparator)?;
+        Ok(Self {
+            number,
+            winning: numbers(winning)?,
+            selected: numbers(selected)?,
+        })
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        Ok(input.iter().map(Card::points).sum())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
+}
+
+#[test]
+fn can_parse() {
+    let input = include_str!("input.txt");
+    for line in input.lines() {
+        let _card: Card = line.parse().unwrap();
+    }
+}
+
+#[test]
+fn can_solve() {
+    let cards: Vec<Card> = include_str!("input.txt")
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+    assert_eq!(cards[0].winning_selected(), 4);
+    assert_eq!(cards[0].points(), 8);
+    assert_eq!(cards[1].winning_selected(), 2);
+    assert_eq!(cards[1].points(), 2);
+    assert_eq!(cards[2].winning_selected(), 2);
+    assert_eq!(cards[2].points(), 2);
+    assert_eq!(cards[3].winning_selected(), 1);
+    assert_eq!(cards[3].points(), 1);
+    assert_eq!(cards[4].winning_selected(), 0);
+    assert_eq!(cards[4].points(), 0);
+    assert_eq!(cards[5].winning_selected(), 0);
+    assert_eq!(cards[5].points(), 0);
+}
+
+#[test]
+fn can_run() {
+    let input = Day04::parse(include_str!("input.txt")).unwrap();
+    assert_eq!(Day04::part1(&input).unwrap(), 13);
+}
@@ -0,0 +1,60 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day05::Day05;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::hint::black_box;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Generate an almanac with random seeds and non-overlapping map ranges.
+fn generate(seeds: usize, ranges: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(5);
+    let seeds: Vec<String> = (0..seeds)
+        .map(|_| rng.gen_range(0..1u64 << 32).to_string())
+        .collect();
+    let mut output = format!("seeds: {}\n", seeds.join(" "));
+    for names in CATEGORIES.windows(2) {
+        output.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+        let mut source = 0;
+        for _ in 0..ranges {
+            source += rng.gen_range(0..1u64 << 20);
+            let length = rng.gen_range(1..1u64 << 24);
+            let destination = rng.gen_range(0..1u64 << 32);
+            output.push_str(&format!("{destination} {source} {length}\n"));
+            source += length;
+        }
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    let inputs = [
+        ("sample", include_str!("../src/input.txt").to_string()),
+        ("generated", generate(1000, 200)),
+    ];
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day05/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day05::parse(input).unwrap();
+        group.bench_function("parse", |b| b.iter(|| Day05::parse(black_box(input))));
+        group.bench_function("part1", |b| b.iter(|| Day05::part1(black_box(&parsed))));
+        group.bench_function("Alamanac::map_full", |b| {
+            let seed = parsed.seeds[0];
+            b.iter(|| parsed.map_full("seed", black_box(seed)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
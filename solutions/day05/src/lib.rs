@@ -0,0 +1,275 @@
+use aoc_common::{
+    split::{blocks, numbers},
+    ParseError, Solution, Unsolved,
+};
+use std::{collections::BTreeMap, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub struct MapRange {
+    pub source: u64,
+    pub destination: u64,
+    pub length: u64,
+}
+
+impl FromStr for MapRange {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut numbers = input.split_whitespace().map(str::parse);
+        let mut next = |name| numbers.next().ok_or(ParseError::Missing(name));
+        let range = MapRange {
+            destination: next("range destination")??,
+            source: next("range source")??,
+            length: next("range length")??,
+        };
+        if range.source.checked_add(range.length).is_none()
+            || range.destination.checked_add(range.length).is_none()
+        {
+            return Err(ParseError::invalid("range", input));
+        }
+        Ok(range)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub source: String,
+    pub destination: String,
+    pub mapping: BTreeMap<u64, MapRange>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input.lines();
+        let header = lines.next().ok_or(ParseError::Missing("map header"))?;
+        let name = header
+            .strip_suffix(" map:")
+            .ok_or_else(|| ParseError::invalid("map header", header))?;
+        let (source, destination) = name
+            .split_once("-to-")
+            .ok_or_else(|| ParseError::invalid("map name", name))?;
+        let mut map = Map {
+            source: source.into(),
+            destination: destination.into(),
+            mapping: Default::default(),
+        };
+        for line in lines {
+            map.add(line.parse()?)
+                .map_err(|_| ParseError::invalid("overlapping range", line))?;
+        }
+        Ok(map)
+    }
+}
+
+impl Map {
+    /// Add a range, handing it back if it overlaps an existing one.
+    pub fn add(&mut self, map: MapRange) -> Result<(), MapRange> {
+        if self.try_map(map.source).is_some() {
+            return Err(map);
+        }
+
+        if self
+            .mapping
+            .range(map.source..map.source.saturating_add(map.length))
+            .next()
+            .is_some()
+        {
+            return Err(map);
+        }
+
+        self.mapping.insert(map.source, map);
+        Ok(())
+    }
+
+    pub fn try_map(&self, value: u64) -> Option<u64> {
+        self.mapping
+            .range(..=value)
+            .next_back()
+            .and_then(|(_, map)| {
+                let offset = value - map.source;
+                (offset < map.length).then(|| map.destination + offset)
+            })
+    }
+
+    pub fn map(&self, value: u64) -> u64 {
+        self.try_map(value).unwrap_or(value)
+    }
+}
+
+#[test]
+fn can_map() {
+    let mut map = Map {
+        source: "seed".into(),
+        destination: "soil".into(),
+        mapping: Default::default(),
+    };
+    map.add(MapRange {
+        source: 98,
+        destination: 50,
+        length: 2,
+    })
+    .unwrap();
+    map.add(MapRange {
+        source: 50,
+        destination: 52,
+        length: 48,
+    })
+    .unwrap();
+
+    for i in 0..50 {
+        assert_eq!(map.map(i), i);
+    }
+
+    for i in 50..98 {
+        assert_eq!(map.map(i), i + 2);
+    }
+
+    for i in 98..100 {
+        assert_eq!(map.map(i), i - 48);
+    }
+
+    for i in 100..200 {
+        assert_eq!(map.map(i), i);
+    }
+
+    let overlapping = MapRange {
+        source: 40,
+        destination: 0,
+        length: 11,
+    };
+    assert!(map.add(overlapping).is_err());
+    assert!(map
+        .add(MapRange {
+            source: 99,
+            ..map.mapping[&98].clone()
+        })
+        .is_err());
+}
+
+#[derive(Debug, Clone)]
+pub struct Alamanac {
+    pub seeds: Vec<u64>,
+    pub maps: BTreeMap<String, Map>,
+}
+
+impl Alamanac {
+    pub fn map_once(&self, source: &str, value: u64) -> Option<(&str, u64)> {
+        self.maps
+            .get(source)
+            .map(|map| (map.destination.as_str(), map.map(value)))
+    }
+
+    pub fn map_full<'a, 'b: 'a>(&'a self, source: &'b str, value: u64) -> (&'a str, u64) {
+        let mut current = (source, value);
+        while let Some((source, value)) = self.map_once(current.0, current.1) {
+            current = (source, value);
+        }
+        current
+    }
+}
+
+impl FromStr for Alamanac {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut blocks = blocks(input);
+        let seeds = blocks.next().ok_or(ParseError::Missing("seeds"))?;
+        let seeds = seeds
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::invalid("seeds", seeds))?;
+
+        let alamanac = Alamanac {
+            seeds: numbers(seeds)?,
+            maps: blocks
+                .map(|block| block.parse().map(|map: Map| (map.source.clone(), map)))
+                .collect::<Result<_, _>>()?,
+        };
+
+        // following the maps must end, or mapping a value never would
+        for source in alamanac.maps.keys() {
+            let mut current = source.as_str();
+            for _ in 0..alamanac.maps.len() {
+                match alamanac.maps.get(current) {
+                    Some(map) => current = &map.destination,
+                    None => break,
+                }
+            }
+            if alamanac.maps.contains_key(current) {
+                return Err(ParseError::invalid("cyclic maps", source.as_str()));
+            }
+        }
+
+        Ok(alamanac)
+    }
+}
+
+#[test]
+fn can_parse() {
+    let input = include_str!("input.txt");
+    let alamanac: Alamanac = input.parse().unwrap();
+    assert_eq!(alamanac.seeds, [79, 14, 55, 13]);
+
+    let error = |input: &str| input.parse::<Alamanac>().unwrap_err().to_string();
+    assert_eq!(
+        error("seeds: 1\n\na-to-b map:\n0 10 5\n1 12 5"),
+        r#"invalid overlapping range: "1 12 5""#
+    );
+    assert_eq!(
+        error("seeds: 1\n\na-to-b map:\n0 18446744073709551615 2"),
+        r#"invalid range: "0 18446744073709551615 2""#
+    );
+    assert_eq!(
+        error("seeds: 1\n\na-to-b map:\n\nb-to-a map:\n"),
+        r#"invalid cyclic maps: "a""#
+    );
+}
+
+#[test]
+fn can_solve() {
+    let input = include_str!("input.txt");
+    let alamanac: Alamanac = input.parse().unwrap();
+    assert_eq!(alamanac.map_once("seed", 79), Some(("soil", 81)));
+    assert_eq!(alamanac.map_once("seed", 14), Some(("soil", 14)));
+    assert_eq!(alamanac.map_once("seed", 55), Some(("soil", 57)));
+    assert_eq!(alamanac.map_once("seed", 13), Some(("soil", 13)));
+
+    assert_eq!(alamanac.map_full("seed", 79), ("location", 82));
+    assert_eq!(alamanac.map_full("seed", 14), ("location", 43));
+    assert_eq!(alamanac.map_full("seed", 55), ("location", 86));
+    assert_eq!(alamanac.map_full("seed", 13), ("location", 35));
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Alamanac;
+    type Error = ParseError;
+    type Part1 = u64;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        input
+            .seeds
+            .iter()
+            .map(|seed| input.map_full("seed", *seed).1)
+            .min()
+            .ok_or(ParseError::Missing("seeds"))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
+}
+
+#[test]
+fn can_run() {
+    let input = Day05::parse(include_str!("input.txt")).unwrap();
+    assert_eq!(Day05::part1(&input).unwrap(), 35);
+}
@@ -0,0 +1,52 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day08::Day08;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::hint::black_box;
+
+/// Name of the node at an index, `AAA` is the first one.
+fn name(index: usize) -> String {
+    let letter = |digit: usize| char::from(b'A' + (digit % 26) as u8);
+    [letter(index / 676), letter(index / 26), letter(index)]
+        .into_iter()
+        .collect()
+}
+
+/// Generate a chain of nodes from `AAA` to `ZZZ`, where going left always
+/// advances and going right sometimes stays on the same node.
+fn generate(nodes: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(8);
+    let mut instructions: String = (0..rng.gen_range(200..300))
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect();
+    instructions.push('L');
+
+    let names: Vec<String> = (0..nodes - 1).map(name).chain(["ZZZ".into()]).collect();
+    let mut output = format!("{instructions}\n\n");
+    for (index, node) in names.iter().enumerate() {
+        let next = &names[(index + 1).min(nodes - 1)];
+        let right = if rng.gen() { next } else { node };
+        output.push_str(&format!("{node} = ({next}, {right})\n"));
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    let inputs = [
+        ("sample1", include_str!("../sample1.txt").to_string()),
+        ("sample2", include_str!("../sample2.txt").to_string()),
+        ("generated", generate(10_000)),
+    ];
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day08/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day08::parse(input).unwrap();
+        group.bench_function("parse", |b| b.iter(|| Day08::parse(black_box(input))));
+        group.bench_function("Input::solve", |b| b.iter(|| black_box(&parsed).solve()));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
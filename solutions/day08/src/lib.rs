@@ -0,0 +1,176 @@
+use aoc_common::{split::blocks, ParseError, Solution, Unsolved};
+use std::{collections::BTreeMap, str::FromStr};
+use thiserror::Error;
+
+/// Error walking the network of nodes.
+#[derive(Error, Debug)]
+pub enum NetworkError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("node {0:?} does not exist")]
+    Node(String),
+    #[error("ZZZ cannot be reached from AAA")]
+    Unreachable,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub struct Directions(Vec<Direction>);
+
+impl FromStr for Directions {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .trim()
+            .chars()
+            .map(|c| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                other => Err(ParseError::invalid("direction", other)),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Nodes(BTreeMap<String, [String; 2]>);
+
+impl FromStr for Nodes {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut nodes = Nodes::default();
+        for line in input.lines() {
+            let (name, options) = line
+                .split_once(" = ")
+                .ok_or_else(|| ParseError::invalid("node", line))?;
+            let options = options.trim_start_matches('(').trim_end_matches(')');
+            let (left, right) = options
+                .split_once(", ")
+                .ok_or_else(|| ParseError::invalid("node options", options))?;
+            nodes.0.insert(name.into(), [left.into(), right.into()]);
+        }
+        Ok(nodes)
+    }
+}
+
+pub struct Input {
+    pub instructions: Directions,
+    pub nodes: Nodes,
+}
+
+impl Input {
+    fn step(&self, node: &str, dir: Direction) -> Result<&str, NetworkError> {
+        let d = self
+            .nodes
+            .0
+            .get(node)
+            .ok_or_else(|| NetworkError::Node(node.into()))?;
+        let n = match dir {
+            Direction::Left => &d[0],
+            Direction::Right => &d[1],
+        };
+        Ok(n)
+    }
+
+    fn sequence<'a>(
+        &'a self,
+        start: &'a str,
+    ) -> impl Iterator<Item = Result<&'a str, NetworkError>> + 'a {
+        self.instructions
+            .0
+            .iter()
+            .cycle()
+            .scan(start, |state, dir| {
+                let next = self.step(state, *dir);
+                if let Ok(next) = next {
+                    *state = next;
+                }
+                Some(next)
+            })
+    }
+
+    pub fn solve(&self) -> Result<usize, NetworkError> {
+        // after visiting every node at every instruction, the walk repeats
+        let limit = self.nodes.0.len().max(1) * self.instructions.0.len();
+        for (steps, state) in (1..=limit).zip(self.sequence("AAA")) {
+            if state? == "ZZZ" {
+                return Ok(steps);
+            }
+        }
+        Err(NetworkError::Unreachable)
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut blocks = blocks(input);
+        let header = blocks.next().ok_or(ParseError::Missing("instructions"))?;
+        let body = blocks.next().ok_or(ParseError::Missing("nodes"))?;
+        Ok(Input {
+            instructions: header.parse()?,
+            nodes: body.parse()?,
+        })
+    }
+}
+
+#[test]
+fn can_parse() {
+    let _input1: Input = include_str!("../sample1.txt").parse().unwrap();
+    let _input1: Input = include_str!("../sample2.txt").parse().unwrap();
+}
+
+#[test]
+fn can_solve() {
+    let input1: Input = include_str!("../sample1.txt").parse().unwrap();
+    assert_eq!(input1.solve().unwrap(), 2);
+
+    let input2: Input = include_str!("../sample2.txt").parse().unwrap();
+    assert_eq!(input2.solve().unwrap(), 6);
+
+    let input: Input = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, CCC)\n"
+        .parse()
+        .unwrap();
+    assert!(matches!(input.solve(), Err(NetworkError::Node(node)) if node == "CCC"));
+
+    let input: Input = "L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n"
+        .parse()
+        .unwrap();
+    assert!(matches!(input.solve(), Err(NetworkError::Unreachable)));
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Input;
+    type Error = NetworkError;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        input.solve()
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
+}
+
+#[test]
+fn can_run() {
+    let input = Day08::parse(include_str!("../sample2.txt")).unwrap();
+    assert_eq!(Day08::part1(&input).unwrap(), 6);
+}
@@ -0,0 +1,56 @@
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day09::{Day09, History};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{hint::black_box, str::FromStr};
+
+/// Generate histories of 21 values of random polynomials of degree up to 6.
+fn generate(histories: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(9);
+    let mut output = String::new();
+    for _ in 0..histories {
+        let coefficients: Vec<i64> = (0..rng.gen_range(1..=7))
+            .map(|_| rng.gen_range(-10..=10))
+            .collect();
+        let values: Vec<String> = (0..21i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+        output.push_str(&values.join(" "));
+        output.push('\n');
+    }
+    output
+}
+
+fn bench(c: &mut Criterion) {
+    let inputs = [
+        ("sample", include_str!("../sample.txt").to_string()),
+        ("generated", generate(1000)),
+    ];
+
+    for (name, input) in &inputs {
+        let mut group = c.benchmark_group(format!("day09/{name}"));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let parsed = Day09::parse(input).unwrap();
+        group.bench_function("parse", |b| b.iter(|| Day09::parse(black_box(input))));
+        group.bench_function("part1", |b| b.iter(|| Day09::part1(black_box(&parsed))));
+        group.bench_function("part2", |b| b.iter(|| Day09::part2(black_box(&parsed))));
+        group.finish();
+    }
+
+    let history = History::from_str(&generate(1)).unwrap();
+    c.bench_function("day09/History::next_value", |b| {
+        b.iter(|| black_box(&history).next_value())
+    });
+    c.bench_function("day09/History::prev_value", |b| {
+        b.iter(|| black_box(&history).prev_value())
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
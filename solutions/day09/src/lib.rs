@@ -0,0 +1,137 @@
+use aoc_common::{
+    split::{numbers, parse_lines},
+    Solution,
+};
+use std::{num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+/// Error parsing or extrapolating histories.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum HistoryError {
+    #[error(transparent)]
+    Parse(#[from] ParseIntError),
+    #[error("extrapolated value does not fit in 64 bits")]
+    Overflow,
+}
+
+pub struct History(Vec<i64>);
+
+impl FromStr for History {
+    type Err = ParseIntError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        numbers(input).map(History)
+    }
+}
+
+impl History {
+    pub fn next_value(&self) -> Result<i64, HistoryError> {
+        if self.0.iter().all(|i| *i == 0) {
+            Ok(0)
+        } else {
+            let next = self.derivative()?.next_value()?;
+            self.0
+                .last()
+                .unwrap()
+                .checked_add(next)
+                .ok_or(HistoryError::Overflow)
+        }
+    }
+
+    pub fn prev_value(&self) -> Result<i64, HistoryError> {
+        if self.0.iter().all(|i| *i == 0) {
+            Ok(0)
+        } else {
+            let prev = self.derivative()?.prev_value()?;
+            self.0
+                .first()
+                .unwrap()
+                .checked_sub(prev)
+                .ok_or(HistoryError::Overflow)
+        }
+    }
+
+    fn derivative(&self) -> Result<Self, HistoryError> {
+        self.0
+            .iter()
+            .zip(self.0.iter().skip(1))
+            .map(|(prev, next)| next.checked_sub(*prev).ok_or(HistoryError::Overflow))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+#[test]
+fn can_parse() {
+    for line in include_str!("../sample.txt").lines() {
+        let _history: History = line.parse().unwrap();
+    }
+}
+
+#[test]
+fn can_solve() {
+    let histories: Vec<History> = include_str!("../sample.txt")
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+    assert_eq!(histories[0].next_value(), Ok(18));
+    assert_eq!(histories[1].next_value(), Ok(28));
+    assert_eq!(histories[2].next_value(), Ok(68));
+}
+
+#[test]
+fn can_solve2() {
+    let histories: Vec<History> = include_str!("../sample.txt")
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+    assert_eq!(histories[2].prev_value(), Ok(5));
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<History>;
+    type Error = HistoryError;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Self::Error> {
+        input.iter().try_fold(0i64, |sum, history| {
+            sum.checked_add(history.next_value()?)
+                .ok_or(HistoryError::Overflow)
+        })
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Self::Error> {
+        input.iter().try_fold(0i64, |sum, history| {
+            sum.checked_add(history.prev_value()?)
+                .ok_or(HistoryError::Overflow)
+        })
+    }
+}
+
+#[test]
+fn can_run() {
+    let input = Day09::parse(include_str!("../sample.txt")).unwrap();
+    assert_eq!(Day09::part1(&input).unwrap(), 114);
+    assert_eq!(Day09::part2(&input).unwrap(), 2);
+}
+
+#[test]
+fn can_report_overflow() {
+    let input = Day09::parse("-9223372036854775808 9223372036854775807\n").unwrap();
+    assert_eq!(Day09::part1(&input), Err(HistoryError::Overflow));
+    assert_eq!(Day09::part2(&input), Err(HistoryError::Overflow));
+
+    // every difference fits, but the extrapolated values do not
+    let input = Day09::parse("0 4611686018427387904 9223372036854775807\n").unwrap();
+    assert_eq!(Day09::part1(&input), Err(HistoryError::Overflow));
+    let input = Day09::parse("9223372036854775807\n9223372036854775807\n").unwrap();
+    assert_eq!(Day09::part1(&input), Err(HistoryError::Overflow));
+}